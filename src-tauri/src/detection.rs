@@ -1,9 +1,18 @@
 use crate::error::AppError;
 use crate::project_info::enrich_project_info;
 use crate::types::Project;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+// Cancellation flag of the scan in progress; a new scan replaces the previous one's
+static ACTIVE_SCAN: OnceLock<Mutex<Option<Arc<AtomicBool>>>> = OnceLock::new();
+
+fn get_active_scan() -> &'static Mutex<Option<Arc<AtomicBool>>> {
+    ACTIVE_SCAN.get_or_init(|| Mutex::new(None))
+}
 
 /// Helper function to check if a file exists in a HashSet of file names
 fn has_file(files: &HashSet<String>, name: &str) -> bool {
@@ -43,60 +52,167 @@ pub fn detect_package_manager(files: &HashSet<String>) -> &'static str {
 }
 
 
+/// Framework tie-breaking order, highest precedence first
+///
+/// When a package shows signs of several frameworks (e.g. a SvelteKit app also
+/// has a vite.config), the first one in this list becomes the `framework`.
+pub const FRAMEWORK_PRECEDENCE: &[&str] = &[
+    "astro",
+    "nextjs",
+    "remix",
+    "solidstart",
+    "angular",
+    "gatsby",
+    "qwik",
+    "vite",
+    "react",
+    "sveltekit",
+    "nuxt",
+];
+
+/// Returns the package.json dependencies that indicate a framework
+fn framework_dependencies(framework: &str) -> &'static [&'static str] {
+    match framework {
+        "astro" => &["astro"],
+        "nextjs" => &["next"],
+        "remix" => &["@remix-run/dev"],
+        // SolidStart 1.0 is `@solidjs/start`; the beta was published as `solid-start`
+        "solidstart" => &["@solidjs/start", "solid-start"],
+        "gatsby" => &["gatsby"],
+        "qwik" => &["@builder.io/qwik", "@builder.io/qwik-city"],
+        "vite" => &["vite"],
+        "react" => &["react-scripts"],
+        "sveltekit" => &["@sveltejs/kit"],
+        "nuxt" => &["nuxt"],
+        _ => &[],
+    }
+}
+
+/// Checks whether package.json declares one of the framework's dependencies
+///
+/// Only `dependencies` and `devDependencies` count: a library that merely
+/// lists the framework as a peer dependency or keyword isn't an app built on it.
+fn has_framework_dependency(path: &Path, framework: &str) -> bool {
+    framework_dependencies(framework)
+        .iter()
+        .any(|dependency| crate::project_info::has_dependency(path, dependency))
+}
+
+/// Checks for the config files that identify a framework
+fn has_framework_config(files: &HashSet<String>, path: &PathBuf, framework: &str) -> bool {
+    match framework {
+        "astro" => {
+            has_file(files, "astro.config.mjs")
+                || has_file(files, "astro.config.js")
+                || has_file(files, "astro.config.ts")
+        }
+        "nextjs" => {
+            has_file(files, "next.config.js")
+                || has_file(files, "next.config.mjs")
+                || has_file(files, "next.config.ts")
+        }
+        // Remix 2 runs on Vite, so a vite.config alongside the Remix compiler also counts
+        "remix" => {
+            has_file(files, "remix.config.js")
+                || has_file(files, "remix.config.mjs")
+                || (has_framework_config(files, path, "vite")
+                    && has_framework_dependency(path, "remix"))
+        }
+        // app.config.* is shared with other tools, so it only counts with SolidStart installed
+        "solidstart" => {
+            (has_file(files, "app.config.ts") || has_file(files, "app.config.js"))
+                && has_framework_dependency(path, "solidstart")
+        }
+        "angular" => has_file(files, "angular.json"),
+        "gatsby" => has_file(files, "gatsby-config.js") || has_file(files, "gatsby-config.ts"),
+        // Qwik ships a vite.config too, so its dependency has to count as strongly as a config
+        "qwik" => has_framework_dependency(path, "qwik"),
+        "vite" => {
+            has_file(files, "vite.config.js")
+                || has_file(files, "vite.config.ts")
+                || has_file(files, "vite.config.mjs")
+        }
+        // Create React App has no config file, so look for its layout plus react-scripts
+        "react" => {
+            has_file(files, "src")
+                && has_file(files, "public")
+                && has_file(files, "package.json")
+                && fs::read_to_string(path.join("package.json"))
+                    .is_ok_and(|content| content.contains("react-scripts"))
+        }
+        "sveltekit" => has_file(files, "svelte.config.js") || has_file(files, "svelte.config.ts"),
+        "nuxt" => has_file(files, "nuxt.config.js") || has_file(files, "nuxt.config.ts"),
+        _ => false,
+    }
+}
+
+/// Detects every framework a package shows signs of, ordered by `precedence`
+///
+/// A framework is a candidate if its config file is present or it's declared
+/// in package.json `dependencies`/`devDependencies`. Config files win over
+/// dependencies, so a package with a framework config always has that
+/// framework first regardless of its dependencies.
+pub fn detect_framework_candidates_with(
+    files: &HashSet<String>,
+    path: &PathBuf,
+    precedence: &[&'static str],
+) -> Vec<&'static str> {
+    let package_json = if has_file(files, "package.json") {
+        crate::project_info::read_package_json(path)
+    } else {
+        None
+    };
+    let has_dependency = |name: &str| {
+        package_json.as_ref().is_some_and(|json| {
+            ["dependencies", "devDependencies"]
+                .iter()
+                .any(|field| json.get(field).and_then(|deps| deps.get(name)).is_some())
+        })
+    };
+
+    let from_config = precedence
+        .iter()
+        .filter(|framework| has_framework_config(files, path, framework));
+    let from_dependencies = precedence.iter().filter(|framework| {
+        framework_dependencies(framework)
+            .iter()
+            .any(|dependency| has_dependency(dependency))
+    });
+
+    let mut candidates: Vec<&'static str> = Vec::new();
+    for framework in from_config.chain(from_dependencies) {
+        if !candidates.contains(framework) {
+            candidates.push(framework);
+        }
+    }
+    candidates
+}
+
+/// Detects every framework a package shows signs of, in the default precedence order
+pub fn detect_framework_candidates(files: &HashSet<String>, path: &PathBuf) -> Vec<&'static str> {
+    detect_framework_candidates_with(files, path, FRAMEWORK_PRECEDENCE)
+}
+
 /// Detects the framework from a directory's file list
 /// 
 /// This function accepts a HashSet of file names to avoid redundant directory reads.
 /// For convenience, use `detect_framework_from_path()` which reads the directory.
 /// Returns a string slice to avoid unnecessary allocations.
 pub fn detect_framework(files: &HashSet<String>, path: &PathBuf) -> &'static str {
-    // Astro
-    if has_file(files, "astro.config.mjs")
-        || has_file(files, "astro.config.js")
-        || has_file(files, "astro.config.ts")
-    {
-        return "astro";
-    }
-
-    // Next.js
-    if has_file(files, "next.config.js")
-        || has_file(files, "next.config.mjs")
-        || has_file(files, "next.config.ts")
-    {
-        return "nextjs";
-    }
-
-    // Vite (check for vite.config.*)
-    if has_file(files, "vite.config.js")
-        || has_file(files, "vite.config.ts")
-        || has_file(files, "vite.config.mjs")
-    {
-        return "vite";
-    }
-
-    // React (Create React App)
-    if has_file(files, "src") && has_file(files, "public") {
-        if has_file(files, "package.json") {
-            let package_json = path.join("package.json");
-            if let Ok(content) = fs::read_to_string(&package_json) {
-                if content.contains("react-scripts") {
-                    return "react";
-                }
-            }
-        }
-    }
-
-    // SvelteKit
-    if has_file(files, "svelte.config.js") || has_file(files, "svelte.config.ts") {
-        return "sveltekit";
-    }
-
-    // Nuxt
-    if has_file(files, "nuxt.config.js") || has_file(files, "nuxt.config.ts") {
-        return "nuxt";
-    }
+    // The top candidate wins; "node" when no framework was detected
+    detect_framework_candidates(files, path)
+        .first()
+        .copied()
+        .unwrap_or("node")
+}
 
-    // Default
-    "node"
+/// Lists all framework candidates for a project directory, top pick first
+pub fn framework_candidates(path: &PathBuf) -> Vec<String> {
+    let files = get_directory_files(path);
+    detect_framework_candidates(&files, path)
+        .into_iter()
+        .map(String::from)
+        .collect()
 }
 
 /// Convenience wrapper that reads the directory and calls `detect_framework()`
@@ -108,7 +224,56 @@ pub fn detect_framework_from_path(path: &PathBuf) -> &'static str {
     detect_framework(&files, path)
 }
 
-pub fn scan_directory(path: &Path) -> Result<Vec<Project>, AppError> {
+/// Convenience wrapper that reads the directory and calls `detect_package_manager()`
+pub fn detect_package_manager_from_path(path: &PathBuf) -> &'static str {
+    let files = get_directory_files(path);
+    detect_package_manager(&files)
+}
+
+/// Stops the scan in progress, which then returns the projects found so far
+pub fn cancel_scan() {
+    if let Ok(active_scan) = get_active_scan().lock() {
+        if let Some(cancelled) = active_scan.as_ref() {
+            cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Scans a directory for projects, registering the scan so `cancel_scan()` can stop it
+///
+/// `max_depth` is how many directory levels below `path` are searched; 1 looks
+/// only at its immediate children.
+pub fn scan_directory(path: &Path, max_depth: usize) -> Result<Vec<Project>, AppError> {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    if let Ok(mut active_scan) = get_active_scan().lock() {
+        *active_scan = Some(Arc::clone(&cancelled));
+    }
+
+    let result = scan_directory_cancellable(path, max_depth, &cancelled);
+
+    if let Ok(mut active_scan) = get_active_scan().lock() {
+        // Leave a newer scan's registration in place
+        if active_scan
+            .as_ref()
+            .is_some_and(|active| Arc::ptr_eq(active, &cancelled))
+        {
+            *active_scan = None;
+        }
+    }
+
+    result
+}
+
+/// Scans a directory for projects until `cancelled` is set
+///
+/// The flag is checked before each entry is inspected and each project is
+/// enriched, so a cancelled scan returns the projects found up to that point,
+/// the ones it didn't get to enrich as detected. Projects are sorted by name.
+pub fn scan_directory_cancellable(
+    path: &Path,
+    max_depth: usize,
+    cancelled: &AtomicBool,
+) -> Result<Vec<Project>, AppError> {
     // Use metadata() for faster existence check (single syscall)
     // This will return IoError if path doesn't exist, NotFound if not a directory
     let metadata = fs::metadata(path).map_err(|e| {
@@ -129,92 +294,225 @@ pub fn scan_directory(path: &Path) -> Result<Vec<Project>, AppError> {
         )));
     }
 
-    // Use iterator chain for better performance and early filtering
-    let projects: Result<Vec<Project>, AppError> = fs::read_dir(path)?
-        .filter_map(|entry| {
-            let entry = match entry {
-                Ok(e) => e,
-                Err(_) => return None,
-            };
-            
-            let project_path = entry.path();
-            
-            // Fast check: use metadata() instead of is_dir() to avoid extra syscall
-            let metadata = match project_path.metadata() {
-                Ok(m) => m,
-                Err(_) => return None,
-            };
-            
-            if !metadata.is_dir() {
-                return None;
-            }
-            
-            // Get directory files once for all checks
-            let dir_files = get_directory_files(&project_path);
-            
-            // Check for Node.js projects
-            if has_file(&dir_files, "package.json") {
-                let package_manager = detect_package_manager(&dir_files).to_string();
-                let framework = detect_framework(&dir_files, &project_path).to_string();
-                let port = crate::port::detect_port(&project_path);
-                let mut project = Project {
-                    name: entry.file_name().to_string_lossy().to_string(),
-                    path: project_path.to_string_lossy().to_string(),
-                    runtime: "Node.js".to_string(),
-                    package_manager: Some(package_manager),
-                    port,
-                    framework: Some(framework),
-                    runtime_version: None,
-                    scripts: None,
-                    size: None,
-                    modified: None,
-                };
-                project = enrich_project_info(project);
-                Some(Ok(project))
-            }
-            // Check for Deno projects
-            else if has_file(&dir_files, "deno.json") || has_file(&dir_files, "deno.jsonc") {
-                let port = crate::port::detect_port_deno(&project_path);
-                let mut project = Project {
-                    name: entry.file_name().to_string_lossy().to_string(),
-                    path: project_path.to_string_lossy().to_string(),
-                    runtime: "Deno".to_string(),
-                    package_manager: None,
-                    port,
-                    framework: Some("deno".to_string()),
-                    runtime_version: None,
-                    scripts: None,
-                    size: None,
-                    modified: None,
-                };
-                project = enrich_project_info(project);
-                Some(Ok(project))
-            }
-            // Check for Bun projects
-            else if has_file(&dir_files, "bun.lockb") || has_file(&dir_files, "bunfig.toml") {
-                let framework = detect_framework(&dir_files, &project_path).to_string();
-                let port = crate::port::detect_port(&project_path);
-                let mut project = Project {
-                    name: entry.file_name().to_string_lossy().to_string(),
-                    path: project_path.to_string_lossy().to_string(),
-                    runtime: "Bun".to_string(),
-                    package_manager: Some("bun".to_string()),
-                    port,
-                    framework: Some(framework),
-                    runtime_version: None,
-                    scripts: None,
-                    size: None,
-                    modified: None,
-                };
-                project = enrich_project_info(project);
-                Some(Ok(project))
-            } else {
-                None
-            }
+    let mut projects = Vec::new();
+    collect_projects(path, max_depth, cancelled, &mut projects)?;
+
+    let mut projects = enrich_in_parallel(projects, cancelled);
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(projects)
+}
+
+/// Enriches projects on a pool of worker threads
+///
+/// Enrichment shells out for runtime versions and walks each tree for its
+/// size, so it dominates scan time. Projects not yet enriched when the scan
+/// is cancelled are returned as detected, without the enriched fields.
+fn enrich_in_parallel(projects: Vec<Project>, cancelled: &AtomicBool) -> Vec<Project> {
+    let workers = std::thread::available_parallelism()
+        .map_or(4, |n| n.get())
+        .min(projects.len());
+    let queue = Mutex::new(projects);
+    let enriched = Mutex::new(Vec::new());
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while !cancelled.load(Ordering::Relaxed) {
+                    let Some(project) = queue.lock().ok().and_then(|mut queue| queue.pop()) else {
+                        break;
+                    };
+                    let project = enrich_project_info(project);
+                    if let Ok(mut enriched) = enriched.lock() {
+                        enriched.push(project);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut enriched = enriched
+        .into_inner()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    enriched.extend(
+        queue
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    );
+    enriched
+}
+
+/// Collects the projects under `dir`, descending up to `depth` levels
+///
+/// Recursion stops at recognized projects so nested packages aren't counted
+/// twice, and skips `SKIP_DIRS` and hidden directories.
+fn collect_projects(
+    dir: &Path,
+    depth: usize,
+    cancelled: &AtomicBool,
+    projects: &mut Vec<Project>,
+) -> Result<(), AppError> {
+    if depth == 0 {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir)?.flatten() {
+        if cancelled.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let project_path = entry.path();
+        
+        // Fast check: use metadata() instead of is_dir() to avoid extra syscall
+        if !project_path.metadata().is_ok_and(|m| m.is_dir()) {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().to_string();
+        if let Some(project) = detect_project(&project_path, &name) {
+            projects.push(project);
+        } else if depth > 1
+            && !name.starts_with('.')
+            && !crate::project_info::SKIP_DIRS.contains(&name.as_str())
+        {
+            // An unreadable subdirectory shouldn't fail the whole scan
+            let _ = collect_projects(&project_path, depth - 1, cancelled, projects);
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the projects under `path` without enriching them
+///
+/// Unlike `scan_directory()`, the walk isn't registered with `cancel_scan()`,
+/// so it can run alongside a scan the user may cancel.
+pub fn detect_projects(path: &Path, max_depth: usize) -> Result<Vec<Project>, AppError> {
+    let mut projects = Vec::new();
+    collect_projects(path, max_depth, &AtomicBool::new(false), &mut projects)?;
+    Ok(projects)
+}
+
+/// Groups projects that are likely copies of each other
+///
+/// Projects match when their package.json `name` and dependency names are the
+/// same (see `project_fingerprint`). Only groups of two or more are returned,
+/// with paths sorted inside each group and groups ordered by their first path.
+pub fn find_duplicate_projects(projects: &[Project]) -> Vec<Vec<String>> {
+    let mut groups: HashMap<u64, Vec<String>> = HashMap::new();
+    for project in projects {
+        if let Some(fingerprint) =
+            crate::project_info::project_fingerprint(Path::new(&project.path))
+        {
+            groups
+                .entry(fingerprint)
+                .or_default()
+                .push(project.path.clone());
+        }
+    }
+
+    let mut duplicates: Vec<Vec<String>> = groups
+        .into_values()
+        .filter(|paths| paths.len() > 1)
+        .map(|mut paths| {
+            paths.sort();
+            paths
         })
         .collect();
+    duplicates.sort();
+    duplicates
+}
+
+/// Manifests that mark a directory as using each runtime, in detection priority order
+const RUNTIME_MANIFESTS: &[(&str, &[&str])] = &[
+    ("Node.js", &["package.json"]),
+    ("Deno", &["deno.json", "deno.jsonc"]),
+    ("Bun", &["bun.lockb", "bunfig.toml"]),
+    ("Rust", &["Cargo.toml"]),
+];
+
+/// Lists every runtime with a manifest in the directory, primary first
+///
+/// Projects migrating between runtimes (e.g. package.json next to deno.json)
+/// report all of them, while `runtime` keeps the one the project is run with.
+fn detect_runtimes(files: &HashSet<String>) -> Vec<String> {
+    RUNTIME_MANIFESTS
+        .iter()
+        .filter(|(_, manifests)| manifests.iter().any(|manifest| has_file(files, manifest)))
+        .map(|(runtime, _)| runtime.to_string())
+        .collect()
+}
+
+/// Detects the project rooted at `project_path`, if it is one
+///
+/// The project isn't enriched yet; `enrich_in_parallel()` does that once the
+/// walk is done.
+fn detect_project(project_path: &PathBuf, name: &str) -> Option<Project> {
+    // Get directory files once for all checks
+    let dir_files = get_directory_files(project_path);
     
-    projects
+    // Check for Node.js projects
+    let project = if has_file(&dir_files, "package.json") {
+        let package_manager = detect_package_manager(&dir_files).to_string();
+        let framework = detect_framework(&dir_files, project_path).to_string();
+        let port = crate::port::detect_port(project_path);
+        Project {
+            name: name.to_string(),
+            path: project_path.to_string_lossy().to_string(),
+            runtime: "Node.js".to_string(),
+            package_manager: Some(package_manager),
+            port,
+            framework: Some(framework),
+            ..Default::default()
+        }
+    }
+    // Check for Deno projects
+    else if has_file(&dir_files, "deno.json") || has_file(&dir_files, "deno.jsonc") {
+        let port = crate::port::detect_port_deno(project_path);
+        Project {
+            name: name.to_string(),
+            path: project_path.to_string_lossy().to_string(),
+            runtime: "Deno".to_string(),
+            package_manager: None,
+            port,
+            framework: Some("deno".to_string()),
+            ..Default::default()
+        }
+    }
+    // Check for Bun projects
+    else if has_file(&dir_files, "bun.lockb") || has_file(&dir_files, "bunfig.toml") {
+        let framework = detect_framework(&dir_files, project_path).to_string();
+        let port = crate::port::detect_port(project_path);
+        Project {
+            name: name.to_string(),
+            path: project_path.to_string_lossy().to_string(),
+            runtime: "Bun".to_string(),
+            package_manager: Some("bun".to_string()),
+            port,
+            framework: Some(framework),
+            ..Default::default()
+        }
+    }
+    // Check for Rust projects
+    else if has_file(&dir_files, "Cargo.toml") {
+        let name = crate::project_info::get_cargo_package_name(project_path)
+            .unwrap_or_else(|| name.to_string());
+        Project {
+            name,
+            path: project_path.to_string_lossy().to_string(),
+            runtime: "Rust".to_string(),
+            package_manager: Some("cargo".to_string()),
+            port: None,
+            framework: None,
+            ..Default::default()
+        }
+    } else {
+        return None;
+    };
+
+    Some(Project {
+        runtimes: detect_runtimes(&dir_files),
+        ..project
+    })
 }
 
 #[cfg(test)]
@@ -313,6 +611,163 @@ mod tests {
         assert_eq!(detect_framework_from_path(&dir_path), "nextjs");
     }
 
+    #[test]
+    fn test_detect_framework_remix() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "remix.config.js", "");
+
+        assert_eq!(detect_framework_from_path(&dir_path), "remix");
+    }
+
+    #[test]
+    fn test_detect_framework_remix_vite() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "vite.config.ts", "");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"@remix-run/dev": "^2.8.0", "vite": "^5.1.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "remix");
+    }
+
+    #[test]
+    fn test_detect_framework_vite_with_remix_mention() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "vite.config.ts", "");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"description": "Port of @remix-run/dev-tools", "devDependencies": {"vite": "^5.1.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "vite");
+    }
+
+    #[test]
+    fn test_detect_framework_remix_dependency_only() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"@remix-run/dev": "^1.19.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "remix");
+    }
+
+    #[test]
+    fn test_detect_framework_solidstart() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "app.config.ts", "");
+        create_temp_file(temp_dir.path(), "vite.config.ts", "");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"@solidjs/start": "^1.0.0", "vinxi": "^0.4.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "solidstart");
+    }
+
+    #[test]
+    fn test_detect_framework_app_config_without_solidstart() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "app.config.ts", "");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"expo": "^50.0.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "node");
+    }
+
+    #[test]
+    fn test_detect_framework_app_config_with_solidstart_keyword() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "app.config.ts", "");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"keywords": ["solid-start"], "peerDependencies": {"@solidjs/start": "^1.0.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "node");
+    }
+
+    #[test]
+    fn test_detect_framework_angular() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "angular.json", "{}");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"@angular/core": "^17.0.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "angular");
+    }
+
+    #[test]
+    fn test_detect_framework_gatsby() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "gatsby-config.ts", "");
+
+        assert_eq!(detect_framework_from_path(&dir_path), "gatsby");
+    }
+
+    #[test]
+    fn test_detect_framework_gatsby_dependency() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"gatsby": "^5.13.0", "react": "^18.2.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "gatsby");
+    }
+
+    #[test]
+    fn test_detect_framework_qwik_before_vite() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "vite.config.ts", "");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"@builder.io/qwik-city": "^1.4.0", "vite": "^5.0.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "qwik");
+    }
+
+    #[test]
+    fn test_detect_framework_qwik_peer_dependency_is_not_an_app() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "vite.config.ts", "");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"keywords": ["@builder.io/qwik"], "peerDependencies": {"@builder.io/qwik": "^1.4.0"}, "devDependencies": {"vite": "^5.0.0"}}"#,
+        );
+
+        assert_eq!(detect_framework_from_path(&dir_path), "vite");
+    }
+
     #[test]
     fn test_detect_framework_vite() {
         let temp_dir = create_temp_dir();
@@ -375,9 +830,52 @@ mod tests {
         assert_eq!(detect_framework_from_path(&dir_path), "astro");
     }
 
+    #[test]
+    fn test_framework_candidates_multiple_dependencies() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "svelte.config.js", "");
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{
+                "dependencies": {"react-scripts": "^5.0.0"},
+                "devDependencies": {"vite": "^5.0.0", "@sveltejs/kit": "^2.0.0", "next": "^14.0.0"}
+            }"#,
+        );
+
+        // The config file puts SvelteKit first, dependencies follow in precedence order
+        assert_eq!(
+            framework_candidates(&dir_path),
+            vec!["sveltekit", "nextjs", "vite", "react"]
+        );
+        assert_eq!(detect_framework_from_path(&dir_path), "sveltekit");
+    }
+
+    #[test]
+    fn test_framework_candidates_custom_precedence() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"vite": "^5.0.0", "astro": "^4.0.0"}}"#,
+        );
+
+        let files = get_directory_files(&dir_path);
+        assert_eq!(
+            detect_framework_candidates(&files, &dir_path),
+            vec!["astro", "vite"]
+        );
+        assert_eq!(
+            detect_framework_candidates_with(&files, &dir_path, &["vite", "astro"]),
+            vec!["vite", "astro"]
+        );
+    }
+
     #[test]
     fn test_scan_directory_nonexistent() {
-        let result = scan_directory(Path::new("/nonexistent/path/12345"));
+        let result = scan_directory(Path::new("/nonexistent/path/12345"), 1);
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
     }
@@ -385,7 +883,7 @@ mod tests {
     #[test]
     fn test_scan_directory_empty() {
         let temp_dir = create_temp_dir();
-        let result = scan_directory(temp_dir.path());
+        let result = scan_directory(temp_dir.path(), 1);
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), 0);
     }
@@ -397,7 +895,7 @@ mod tests {
         fs::create_dir(&project_dir).expect("Failed to create project dir");
         create_temp_file(&project_dir, "package.json", r#"{"name": "test-project"}"#);
 
-        let result = scan_directory(temp_dir.path());
+        let result = scan_directory(temp_dir.path(), 1);
         assert!(result.is_ok());
         let projects = result.unwrap();
         assert_eq!(projects.len(), 1);
@@ -412,7 +910,7 @@ mod tests {
         fs::create_dir(&project_dir).expect("Failed to create project dir");
         create_temp_file(&project_dir, "deno.json", r#"{}"#);
 
-        let result = scan_directory(temp_dir.path());
+        let result = scan_directory(temp_dir.path(), 1);
         assert!(result.is_ok());
         let projects = result.unwrap();
         assert_eq!(projects.len(), 1);
@@ -427,7 +925,7 @@ mod tests {
         fs::create_dir(&project_dir).expect("Failed to create project dir");
         create_temp_file(&project_dir, "bun.lockb", "");
 
-        let result = scan_directory(temp_dir.path());
+        let result = scan_directory(temp_dir.path(), 1);
         assert!(result.is_ok());
         let projects = result.unwrap();
         assert_eq!(projects.len(), 1);
@@ -435,6 +933,73 @@ mod tests {
         assert_eq!(projects[0].runtime, "Bun");
     }
 
+    #[test]
+    fn test_scan_directory_rust_project() {
+        let temp_dir = create_temp_dir();
+        let project_dir = temp_dir.path().join("rust-project");
+        fs::create_dir(&project_dir).expect("Failed to create project dir");
+        create_temp_file(
+            &project_dir,
+            "Cargo.toml",
+            "[package]\nname = \"my-crate\"\nversion = \"0.1.0\"\n",
+        );
+
+        let result = scan_directory(temp_dir.path(), 1);
+        assert!(result.is_ok());
+        let projects = result.unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "my-crate");
+        assert_eq!(projects[0].runtime, "Rust");
+        assert_eq!(projects[0].package_manager, Some("cargo".to_string()));
+        assert_eq!(projects[0].port, None);
+    }
+
+    #[test]
+    fn test_find_duplicate_projects() {
+        let temp_dir = create_temp_dir();
+        let package_json =
+            r#"{"name": "shop", "dependencies": {"react": "^18.2.0", "next": "14.0.0"}}"#;
+        for dir in ["shop", "shop-copy"] {
+            let project_dir = temp_dir.path().join(dir);
+            fs::create_dir(&project_dir).expect("Failed to create project dir");
+            create_temp_file(&project_dir, "package.json", package_json);
+        }
+        // Same name, different dependencies
+        let other_dir = temp_dir.path().join("shop-v2");
+        fs::create_dir(&other_dir).expect("Failed to create project dir");
+        create_temp_file(
+            &other_dir,
+            "package.json",
+            r#"{"name": "shop", "dependencies": {"react": "^18.2.0"}}"#,
+        );
+
+        let projects = detect_projects(temp_dir.path(), 1).unwrap();
+        let duplicates = find_duplicate_projects(&projects);
+        assert_eq!(duplicates.len(), 1);
+        let names: Vec<&str> = duplicates[0]
+            .iter()
+            .map(|path| path.rsplit('/').next().unwrap())
+            .collect();
+        assert_eq!(names, vec!["shop", "shop-copy"]);
+    }
+
+    #[test]
+    fn test_scan_directory_lists_all_runtimes() {
+        let temp_dir = create_temp_dir();
+        let project_dir = temp_dir.path().join("migrating-project");
+        fs::create_dir(&project_dir).expect("Failed to create project dir");
+        create_temp_file(&project_dir, "package.json", r#"{"name": "migrating"}"#);
+        create_temp_file(&project_dir, "deno.json", "{}");
+
+        let projects = scan_directory(temp_dir.path(), 1).unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].runtime, "Node.js");
+        assert_eq!(
+            projects[0].runtimes,
+            vec!["Node.js".to_string(), "Deno".to_string()]
+        );
+    }
+
     #[test]
     fn test_scan_directory_multiple_projects() {
         let temp_dir = create_temp_dir();
@@ -449,18 +1014,94 @@ mod tests {
         fs::create_dir(&deno_dir).expect("Failed to create deno dir");
         create_temp_file(&deno_dir, "deno.json", r#"{}"#);
 
-        let result = scan_directory(temp_dir.path());
+        let result = scan_directory(temp_dir.path(), 1);
         assert!(result.is_ok());
         let projects = result.unwrap();
         assert_eq!(projects.len(), 2);
     }
 
+    #[test]
+    fn test_scan_directory_enriches_and_sorts_by_name() {
+        let temp_dir = create_temp_dir();
+        for name in ["charlie", "alpha", "delta", "bravo"] {
+            let project_dir = temp_dir.path().join(name);
+            fs::create_dir(&project_dir).expect("Failed to create project dir");
+            create_temp_file(&project_dir, "package.json", r#"{}"#);
+        }
+
+        let projects = scan_directory(temp_dir.path(), 1).unwrap();
+        let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "bravo", "charlie", "delta"]);
+        assert!(projects.iter().all(|p| p.size.is_some()));
+    }
+
+    #[test]
+    fn test_scan_directory_recursive() {
+        let temp_dir = create_temp_dir();
+
+        // ~/code/<org>/<project>
+        let project_dir = temp_dir.path().join("acme/web");
+        fs::create_dir_all(&project_dir).expect("Failed to create project dir");
+        create_temp_file(&project_dir, "package.json", r#"{}"#);
+
+        // Nested packages belong to the project already found
+        let nested_dir = project_dir.join("packages/ui");
+        fs::create_dir_all(&nested_dir).expect("Failed to create nested dir");
+        create_temp_file(&nested_dir, "package.json", r#"{}"#);
+
+        // Dependencies aren't projects
+        let dependency_dir = temp_dir.path().join("acme/node_modules/left-pad");
+        fs::create_dir_all(&dependency_dir).expect("Failed to create dependency dir");
+        create_temp_file(&dependency_dir, "package.json", r#"{}"#);
+
+        assert!(scan_directory(temp_dir.path(), 1).unwrap().is_empty());
+
+        let projects = scan_directory(temp_dir.path(), 4).unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "web");
+    }
+
+    #[test]
+    fn test_scan_directory_cancellable_stops_when_cancelled() {
+        let temp_dir = create_temp_dir();
+        for name in ["a", "b", "c"] {
+            let project_dir = temp_dir.path().join(name);
+            fs::create_dir(&project_dir).expect("Failed to create project dir");
+            create_temp_file(&project_dir, "package.json", r#"{}"#);
+        }
+
+        let cancelled = AtomicBool::new(false);
+        let projects = scan_directory_cancellable(temp_dir.path(), 1, &cancelled).unwrap();
+        assert_eq!(projects.len(), 3);
+
+        cancelled.store(true, Ordering::Relaxed);
+        let projects = scan_directory_cancellable(temp_dir.path(), 1, &cancelled).unwrap();
+        assert!(projects.is_empty());
+    }
+
+    #[test]
+    fn test_enrich_in_parallel_keeps_projects_when_cancelled() {
+        let projects: Vec<Project> = ["a", "b"]
+            .iter()
+            .map(|name| Project {
+                name: name.to_string(),
+                path: format!("/nonexistent/{}", name),
+                ..Default::default()
+            })
+            .collect();
+
+        let enriched = enrich_in_parallel(projects, &AtomicBool::new(true));
+        let mut names: Vec<&str> = enriched.iter().map(|p| p.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["a", "b"]);
+    }
+
     #[test]
     fn test_scan_directory_ignores_files() {
         let temp_dir = create_temp_dir();
         create_temp_file(temp_dir.path(), "not-a-project.txt", "content");
 
-        let result = scan_directory(temp_dir.path());
+        let result = scan_directory(temp_dir.path(), 1);
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), 0);
     }
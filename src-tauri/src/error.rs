@@ -16,6 +16,8 @@ pub enum AppError {
     NotFound(String),
     #[serde(rename = "utf8_error")]
     Utf8Error(String),
+    #[serde(rename = "permission_denied")]
+    PermissionDenied(String),
 }
 
 impl fmt::Display for AppError {
@@ -27,6 +29,7 @@ impl fmt::Display for AppError {
             AppError::ParseError(msg) => write!(f, "Parse error: {}", msg),
             AppError::NotFound(msg) => write!(f, "Not found: {}", msg),
             AppError::Utf8Error(msg) => write!(f, "UTF-8 error: {}", msg),
+            AppError::PermissionDenied(msg) => write!(f, "Permission denied: {}", msg),
         }
     }
 }
@@ -76,6 +79,10 @@ mod tests {
         let not_found = AppError::NotFound("test not found".to_string());
         assert!(not_found.to_string().contains("Not found"));
         assert!(not_found.to_string().contains("test not found"));
+
+        let denied = AppError::PermissionDenied("test denied".to_string());
+        assert!(denied.to_string().contains("Permission denied"));
+        assert!(denied.to_string().contains("test denied"));
     }
 
     #[test]
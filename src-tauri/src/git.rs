@@ -0,0 +1,834 @@
+use crate::error::AppError;
+use crate::types::{GitBranches, GitStatus, OpenRemotesResult, Project};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command as StdCommand;
+
+/// Finds the root of the git repository enclosing `path`
+///
+/// Walks up the ancestors of `path` (including `path` itself) looking for a
+/// `.git` entry. Both `.git` directories and `.git` files (worktrees and
+/// submodules) are recognized.
+pub fn find_repo_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|ancestor| ancestor.join(".git").exists())
+        .map(|ancestor| ancestor.to_path_buf())
+}
+
+/// Resolves the directory holding the repository's git metadata (config, refs)
+///
+/// For a regular repository this is `<root>/.git`. For worktrees and submodules
+/// `.git` is a file containing `gitdir: <path>`, which is followed (and, for
+/// worktrees, resolved to the shared `commondir`).
+fn resolve_git_dir(repo_root: &Path) -> Option<PathBuf> {
+    let dot_git = repo_root.join(".git");
+    let metadata = fs::metadata(&dot_git).ok()?;
+
+    if metadata.is_dir() {
+        return Some(dot_git);
+    }
+
+    let content = fs::read_to_string(&dot_git).ok()?;
+    let gitdir = content.trim().strip_prefix("gitdir:")?.trim();
+    let gitdir = repo_root.join(gitdir);
+
+    // Worktrees keep the shared config in the directory referenced by `commondir`
+    match fs::read_to_string(gitdir.join("commondir")) {
+        Ok(commondir) => Some(gitdir.join(commondir.trim())),
+        Err(_) => Some(gitdir),
+    }
+}
+
+/// Parses the `[remote "<name>"]` sections of a git config file
+///
+/// Returns `(remote name, url)` pairs in the order they appear.
+fn parse_remotes(config: &str) -> Vec<(String, String)> {
+    let mut remotes = Vec::new();
+    let mut current_remote: Option<String> = None;
+
+    for line in config.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+
+        if trimmed.starts_with('[') {
+            current_remote = trimmed
+                .strip_prefix("[remote")
+                .and_then(|rest| rest.trim().strip_suffix(']'))
+                .map(|name| name.trim().trim_matches('"').to_string());
+            continue;
+        }
+
+        if let Some(remote) = &current_remote {
+            if let Some((key, value)) = trimmed.split_once('=') {
+                if key.trim() == "url" {
+                    remotes.push((remote.clone(), value.trim().to_string()));
+                }
+            }
+        }
+    }
+
+    remotes
+}
+
+/// Reads the URL configured for the given remote of the repository enclosing `path`
+pub fn get_remote_url(path: &Path, remote: &str) -> Option<String> {
+    let repo_root = find_repo_root(path)?;
+    let git_dir = resolve_git_dir(&repo_root)?;
+    let config = fs::read_to_string(git_dir.join("config")).ok()?;
+
+    parse_remotes(&config)
+        .into_iter()
+        .find(|(name, _)| name == remote)
+        .map(|(_, url)| url)
+}
+
+/// Checks whether the repository enclosing `path` is a fork
+///
+/// A fork has an `upstream` remote in addition to `origin`. Returns None when
+/// `path` isn't inside a git repository.
+pub fn is_fork(path: &Path) -> Option<bool> {
+    find_repo_root(path)?;
+
+    Some(get_remote_url(path, "origin").is_some() && get_remote_url(path, "upstream").is_some())
+}
+
+/// Converts a git remote URL into the repository's web URL
+///
+/// Handles the common remote forms:
+/// - `git@github.com:owner/repo.git`
+/// - `ssh://git@github.com/owner/repo.git`
+/// - `https://github.com/owner/repo.git`
+pub fn remote_to_web_url(remote_url: &str) -> Option<String> {
+    let remote_url = remote_url.trim();
+
+    let (host, repo_path) = if let Some(rest) = remote_url
+        .strip_prefix("https://")
+        .or_else(|| remote_url.strip_prefix("http://"))
+        .or_else(|| remote_url.strip_prefix("ssh://"))
+        .or_else(|| remote_url.strip_prefix("git://"))
+    {
+        let (host, repo_path) = rest.split_once('/')?;
+        // Drop credentials (`git@`, `user:token@`) and explicit ports
+        let host = host.rsplit('@').next().unwrap_or(host);
+        let host = host.split(':').next().unwrap_or(host);
+        (host, repo_path)
+    } else {
+        // scp-like syntax: [user@]host:owner/repo.git
+        let (user_host, repo_path) = remote_url.split_once(':')?;
+        let host = user_host.rsplit('@').next().unwrap_or(user_host);
+        (host, repo_path)
+    };
+
+    let repo_path = repo_path.trim_end_matches('/');
+    let repo_path = repo_path.strip_suffix(".git").unwrap_or(repo_path);
+
+    if host.is_empty() || repo_path.is_empty() {
+        return None;
+    }
+
+    Some(format!("https://{}/{}", host, repo_path))
+}
+
+/// Checks whether the working tree has uncommitted changes
+///
+/// Returns None when `path` isn't inside a git repository or git isn't available.
+pub fn is_dirty(path: &Path) -> Option<bool> {
+    find_repo_root(path)?;
+
+    let output = StdCommand::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(path)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(!output.stdout.is_empty())
+}
+
+/// Runs a git subcommand in `path` and returns its stdout
+fn run_git(path: &Path, args: &[&str]) -> Result<String, AppError> {
+    let output = StdCommand::new("git")
+        .args(args)
+        .current_dir(path)
+        .output()
+        .map_err(|e| AppError::CommandError(format!("Failed to run git: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(AppError::CommandError(format!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Returns the commit time (Unix seconds) of the repository's first commit
+///
+/// Uses the earliest root commit when history has several. Returns None when
+/// `path` isn't inside a git repository or it has no commits yet.
+pub fn first_commit_time(path: &Path) -> Option<i64> {
+    find_repo_root(path)?;
+
+    run_git(path, &["log", "--max-parents=0", "--format=%ct", "HEAD"])
+        .ok()?
+        .lines()
+        .filter_map(|line| line.trim().parse::<i64>().ok())
+        .min()
+}
+
+/// Returns the repository root, or NotFound when `path` isn't inside a git repository
+fn require_repo(path: &Path) -> Result<PathBuf, AppError> {
+    find_repo_root(path).ok_or_else(|| {
+        AppError::NotFound(format!("Not a git repository: {}", path.display()))
+    })
+}
+
+/// Lists the local branches of the repository enclosing `path`
+///
+/// The current branch is returned separately; it's None for a detached HEAD.
+pub fn git_branches(path: &Path) -> Result<GitBranches, AppError> {
+    require_repo(path)?;
+
+    let branches = run_git(path, &["branch", "--format=%(refname:short)"])?
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect();
+
+    let current = run_git(path, &["branch", "--show-current"])
+        .ok()
+        .map(|branch| branch.trim().to_string())
+        .filter(|branch| !branch.is_empty());
+
+    Ok(GitBranches { current, branches })
+}
+
+/// Decides whether a checkout may proceed given the working tree state
+///
+/// A dirty working tree is refused unless `force` is set, since switching
+/// branches could carry over or conflict with uncommitted work.
+fn ensure_checkout_allowed(dirty: Option<bool>, force: bool) -> Result<(), AppError> {
+    match dirty {
+        Some(true) if !force => Err(AppError::CommandError(
+            "Working tree has uncommitted changes; commit or stash them before switching branches"
+                .to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Switches the repository enclosing `path` to `branch`
+///
+/// Returns the branch that is checked out afterwards. Only existing local
+/// branches are accepted. With `force`, a dirty working tree doesn't block
+/// the switch; git still refuses if the local changes would be overwritten.
+pub fn git_checkout(path: &Path, branch: &str, force: bool) -> Result<String, AppError> {
+    crate::validation::validate_branch_name(branch)?;
+    require_repo(path)?;
+
+    let branch_ref = format!("refs/heads/{}", branch);
+    if run_git(path, &["rev-parse", "--verify", "--quiet", &branch_ref]).is_err() {
+        return Err(AppError::NotFound(format!("Branch not found: {}", branch)));
+    }
+
+    ensure_checkout_allowed(is_dirty(path), force)?;
+
+    // The trailing `--` stops git from treating a name that matches a file as a pathspec
+    run_git(path, &["checkout", "--quiet", branch, "--"])?;
+
+    Ok(run_git(path, &["branch", "--show-current"])?
+        .trim()
+        .to_string())
+}
+
+/// Reads the `bugs` URL from package.json
+///
+/// The field can either be a string or an object with a `url` key.
+fn get_package_json_bugs_url(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path.join("package.json")).ok()?;
+    let json_value = serde_json::from_str::<serde_json::Value>(&content).ok()?;
+    let bugs = json_value.get("bugs")?;
+
+    bugs.as_str()
+        .or_else(|| bugs.get("url").and_then(|url| url.as_str()))
+        .map(|url| url.to_string())
+        .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+}
+
+/// Picks the issue tracker URL from the available sources
+///
+/// The package.json `bugs.url` field is preferred because it's an explicit
+/// declaration. Otherwise the URL is derived from the remote for hosts with a
+/// known `/issues` page (GitHub and GitLab).
+fn resolve_issues_url(bugs_url: Option<String>, remote_url: Option<&str>) -> Option<String> {
+    if bugs_url.is_some() {
+        return bugs_url;
+    }
+
+    let web_url = remote_to_web_url(remote_url?)?;
+    if web_url.contains("github.com") || web_url.contains("gitlab") {
+        Some(format!("{}/issues", web_url))
+    } else {
+        None
+    }
+}
+
+/// Opens the project's issue tracker in the browser
+pub fn open_issues(path: &Path) -> Result<(), AppError> {
+    let remote_url = get_remote_url(path, "origin");
+    let issues_url = resolve_issues_url(get_package_json_bugs_url(path), remote_url.as_deref())
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "No issue tracker found for project: {}",
+                path.display()
+            ))
+        })?;
+
+    crate::quick_actions::open_url(&issues_url)
+}
+
+/// Resolves the web URL of the project's `origin` remote
+pub fn remote_web_url(path: &Path) -> Result<String, AppError> {
+    get_remote_url(path, "origin")
+        .as_deref()
+        .and_then(remote_to_web_url)
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "No web URL for the origin remote of project: {}",
+                path.display()
+            ))
+        })
+}
+
+/// Runs `open` for each path, collecting which succeeded and why the others failed
+fn open_each(
+    paths: &[String],
+    mut open: impl FnMut(&str) -> Result<(), AppError>,
+) -> OpenRemotesResult {
+    let mut result = OpenRemotesResult::default();
+
+    for path in paths {
+        match open(path) {
+            Ok(()) => result.opened.push(path.clone()),
+            Err(error) => result.failed.push((path.clone(), error)),
+        }
+    }
+
+    result
+}
+
+/// Opens the remote of every project in the browser
+///
+/// A path that fails validation or has no web remote doesn't stop the rest;
+/// it's reported in `failed` instead.
+pub fn open_remotes(paths: &[String]) -> OpenRemotesResult {
+    open_each(paths, |path| {
+        let validated_path = crate::validation::validate_directory_path(path)?;
+        let web_url = remote_web_url(&validated_path)?;
+        crate::quick_actions::open_url(&web_url)
+    })
+}
+
+/// Parses the output of `git status --porcelain=v2 --branch -z`
+///
+/// Entries are NUL-terminated and paths are never quoted, so any file name
+/// survives as is.
+fn parse_porcelain_v2(output: &str) -> GitStatus {
+    let mut status = GitStatus::default();
+    let mut entries = output.split('\0');
+
+    while let Some(entry) = entries.next() {
+        if let Some(counts) = entry.strip_prefix("# branch.ab ") {
+            // Format: `# branch.ab +<ahead> -<behind>`
+            for count in counts.split_whitespace() {
+                if let Some(ahead) = count.strip_prefix('+') {
+                    status.ahead = ahead.parse().unwrap_or(0);
+                } else if let Some(behind) = count.strip_prefix('-') {
+                    status.behind = behind.parse().unwrap_or(0);
+                }
+            }
+            continue;
+        }
+
+        // Field counts before the path differ per entry type; the path may contain spaces
+        let xy = entry.split(' ').nth(1);
+        let path = match entry.chars().next() {
+            Some('1') => entry.splitn(9, ' ').nth(8),
+            // Renames/copies are followed by the original path as its own entry
+            Some('2') => {
+                entries.next();
+                entry.splitn(10, ' ').nth(9)
+            }
+            // Merge conflicts need resolving in the working tree, so they count as modified
+            Some('u') => {
+                if let Some(path) = entry.splitn(11, ' ').nth(10) {
+                    status.modified.push(path.to_string());
+                }
+                continue;
+            }
+            Some('?') => {
+                if let Some(path) = entry.strip_prefix("? ") {
+                    status.untracked.push(path.to_string());
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        let (Some(xy), Some(path)) = (xy, path) else {
+            continue;
+        };
+
+        let mut flags = xy.chars();
+        if flags.next().is_some_and(|index| index != '.') {
+            status.staged.push(path.to_string());
+        }
+        if flags.next().is_some_and(|worktree| worktree != '.') {
+            status.modified.push(path.to_string());
+        }
+    }
+
+    status
+}
+
+/// Returns the staged, modified and untracked files plus upstream divergence
+pub fn git_status(path: &Path) -> Result<GitStatus, AppError> {
+    require_repo(path)?;
+
+    let output = run_git(path, &["status", "--porcelain=v2", "--branch", "-z"])?;
+    Ok(parse_porcelain_v2(&output))
+}
+
+/// Group key for projects that aren't inside a git repository
+pub const NO_REPO_KEY: &str = "no-repo";
+
+/// Groups projects by the root of their enclosing git repository
+///
+/// Projects sharing a monorepo end up under the same root; projects outside
+/// any repository are grouped under [`NO_REPO_KEY`].
+pub fn group_by_repo(projects: Vec<Project>) -> HashMap<String, Vec<Project>> {
+    let mut groups: HashMap<String, Vec<Project>> = HashMap::new();
+
+    for project in projects {
+        let key = find_repo_root(Path::new(&project.path))
+            .map(|root| root.to_string_lossy().to_string())
+            .unwrap_or_else(|| NO_REPO_KEY.to_string());
+        groups.entry(key).or_default().push(project);
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_temp_dir() -> TempDir {
+        tempfile::tempdir().expect("Failed to create temp directory")
+    }
+
+    fn create_temp_file(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let file_path = dir.join(name);
+        fs::write(&file_path, content).expect("Failed to write temp file");
+        file_path
+    }
+
+    fn create_git_config(dir: &Path, config: &str) {
+        fs::create_dir_all(dir.join(".git")).expect("Failed to create .git dir");
+        create_temp_file(&dir.join(".git"), "config", config);
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = StdCommand::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("Failed to run git")
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn init_repo(dir: &Path) {
+        git(dir, &["init", "-q"]);
+        git(dir, &["checkout", "-q", "-b", "main"]);
+        git(dir, &["commit", "-q", "--allow-empty", "-m", "init"]);
+    }
+
+    #[test]
+    fn test_first_commit_time() {
+        let temp_dir = create_temp_dir();
+        assert_eq!(first_commit_time(temp_dir.path()), None);
+
+        git(temp_dir.path(), &["init", "-q"]);
+        let status = StdCommand::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(["commit", "-q", "--allow-empty", "-m", "init"])
+            .env("GIT_COMMITTER_DATE", "@1600000000 +0000")
+            .current_dir(temp_dir.path())
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+        git(
+            temp_dir.path(),
+            &["commit", "-q", "--allow-empty", "-m", "second"],
+        );
+
+        assert_eq!(first_commit_time(temp_dir.path()), Some(1600000000));
+    }
+
+    #[test]
+    fn test_find_repo_root_from_nested_dir() {
+        let temp_dir = create_temp_dir();
+        fs::create_dir(temp_dir.path().join(".git")).expect("Failed to create .git dir");
+        let nested = temp_dir.path().join("packages").join("app");
+        fs::create_dir_all(&nested).expect("Failed to create nested dir");
+
+        assert_eq!(find_repo_root(&nested), Some(temp_dir.path().to_path_buf()));
+    }
+
+    #[test]
+    fn test_parse_remotes() {
+        let config = "[core]\n\tbare = false\n[remote \"origin\"]\n\turl = git@github.com:me/app.git\n\tfetch = +refs/heads/*:refs/remotes/origin/*\n[branch \"main\"]\n\tremote = origin\n";
+        assert_eq!(
+            parse_remotes(config),
+            vec![("origin".to_string(), "git@github.com:me/app.git".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_get_remote_url() {
+        let temp_dir = create_temp_dir();
+        create_git_config(
+            temp_dir.path(),
+            "[remote \"origin\"]\n\turl = https://github.com/me/app.git\n",
+        );
+
+        assert_eq!(
+            get_remote_url(temp_dir.path(), "origin"),
+            Some("https://github.com/me/app.git".to_string())
+        );
+        assert_eq!(get_remote_url(temp_dir.path(), "upstream"), None);
+    }
+
+    #[test]
+    fn test_is_fork_with_upstream_remote() {
+        let temp_dir = create_temp_dir();
+        create_git_config(
+            temp_dir.path(),
+            "[remote \"origin\"]\n\turl = git@github.com:me/app.git\n\
+             [remote \"upstream\"]\n\turl = https://github.com/owner/app.git\n",
+        );
+
+        assert_eq!(is_fork(temp_dir.path()), Some(true));
+        assert_eq!(
+            get_remote_url(temp_dir.path(), "upstream"),
+            Some("https://github.com/owner/app.git".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_fork_origin_only() {
+        let temp_dir = create_temp_dir();
+        create_git_config(
+            temp_dir.path(),
+            "[remote \"origin\"]\n\turl = https://github.com/me/app.git\n",
+        );
+        assert_eq!(is_fork(temp_dir.path()), Some(false));
+
+        let not_a_repo = create_temp_dir();
+        assert_eq!(is_fork(not_a_repo.path()), None);
+    }
+
+    #[test]
+    fn test_remote_to_web_url() {
+        assert_eq!(
+            remote_to_web_url("git@github.com:me/app.git"),
+            Some("https://github.com/me/app".to_string())
+        );
+        assert_eq!(
+            remote_to_web_url("https://gitlab.com/group/sub/app.git"),
+            Some("https://gitlab.com/group/sub/app".to_string())
+        );
+        assert_eq!(
+            remote_to_web_url("ssh://git@github.com:22/me/app.git"),
+            Some("https://github.com/me/app".to_string())
+        );
+        assert_eq!(remote_to_web_url("not a url"), None);
+    }
+
+    #[test]
+    fn test_resolve_issues_url_prefers_bugs_url() {
+        let url = resolve_issues_url(
+            Some("https://tracker.example.com/app".to_string()),
+            Some("git@github.com:me/app.git"),
+        );
+        assert_eq!(url, Some("https://tracker.example.com/app".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_issues_url_from_remote() {
+        assert_eq!(
+            resolve_issues_url(None, Some("git@github.com:me/app.git")),
+            Some("https://github.com/me/app/issues".to_string())
+        );
+        assert_eq!(
+            resolve_issues_url(None, Some("https://gitlab.com/me/app.git")),
+            Some("https://gitlab.com/me/app/issues".to_string())
+        );
+        // Unknown hosts have no conventional issues page
+        assert_eq!(
+            resolve_issues_url(None, Some("git@git.example.com:me/app.git")),
+            None
+        );
+        assert_eq!(resolve_issues_url(None, None), None);
+    }
+
+    #[test]
+    fn test_get_package_json_bugs_url_forms() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"bugs": {"url": "https://github.com/me/app/issues"}}"#,
+        );
+        assert_eq!(
+            get_package_json_bugs_url(temp_dir.path()),
+            Some("https://github.com/me/app/issues".to_string())
+        );
+
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"bugs": "https://example.com/bugs"}"#,
+        );
+        assert_eq!(
+            get_package_json_bugs_url(temp_dir.path()),
+            Some("https://example.com/bugs".to_string())
+        );
+    }
+
+    #[test]
+    fn test_open_issues_not_found() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), "package.json", r#"{}"#);
+
+        // Only meaningful when the temp dir isn't nested inside a git checkout
+        if find_repo_root(temp_dir.path()).is_none() {
+            let result = open_issues(temp_dir.path());
+            assert!(matches!(result, Err(AppError::NotFound(_))));
+        }
+    }
+
+    #[test]
+    fn test_open_each_collects_partial_failures() {
+        let with_remote = create_temp_dir();
+        create_git_config(
+            with_remote.path(),
+            "[remote \"origin\"]\n\turl = git@github.com:me/app.git\n",
+        );
+        let without_remote = create_temp_dir();
+        create_git_config(without_remote.path(), "[core]\n\tbare = false\n");
+
+        let paths = vec![
+            with_remote.path().to_string_lossy().to_string(),
+            without_remote.path().to_string_lossy().to_string(),
+        ];
+        let mut opened_urls = Vec::new();
+        let result = open_each(&paths, |path| {
+            opened_urls.push(remote_web_url(Path::new(path))?);
+            Ok(())
+        });
+
+        assert_eq!(opened_urls, vec!["https://github.com/me/app"]);
+        assert_eq!(result.opened, vec![paths[0].clone()]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].0, paths[1]);
+        assert!(matches!(result.failed[0].1, AppError::NotFound(_)));
+    }
+
+    #[test]
+    fn test_git_branches_lists_local_branches() {
+        let temp_dir = create_temp_dir();
+        init_repo(temp_dir.path());
+        git(temp_dir.path(), &["branch", "feature/login"]);
+        git(temp_dir.path(), &["branch", "fix-typo"]);
+
+        let branches = git_branches(temp_dir.path()).expect("Failed to list branches");
+        assert_eq!(branches.current, Some("main".to_string()));
+        assert_eq!(
+            branches.branches,
+            vec![
+                "feature/login".to_string(),
+                "fix-typo".to_string(),
+                "main".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_git_branches_not_a_repo() {
+        let temp_dir = create_temp_dir();
+
+        if find_repo_root(temp_dir.path()).is_none() {
+            let result = git_branches(temp_dir.path());
+            assert!(matches!(result, Err(AppError::NotFound(_))));
+        }
+    }
+
+    #[test]
+    fn test_ensure_checkout_allowed() {
+        assert!(ensure_checkout_allowed(Some(false), false).is_ok());
+        assert!(ensure_checkout_allowed(None, false).is_ok());
+        assert!(ensure_checkout_allowed(Some(true), true).is_ok());
+
+        let err = ensure_checkout_allowed(Some(true), false).unwrap_err();
+        assert!(matches!(err, AppError::CommandError(_)));
+        assert!(err.to_string().contains("uncommitted changes"));
+    }
+
+    #[test]
+    fn test_git_checkout_switches_branch() {
+        let temp_dir = create_temp_dir();
+        init_repo(temp_dir.path());
+        git(temp_dir.path(), &["branch", "feature"]);
+
+        let branch = git_checkout(temp_dir.path(), "feature", false).expect("Checkout failed");
+        assert_eq!(branch, "feature");
+    }
+
+    #[test]
+    fn test_git_checkout_refuses_dirty_tree() {
+        let temp_dir = create_temp_dir();
+        init_repo(temp_dir.path());
+        git(temp_dir.path(), &["branch", "feature"]);
+        create_temp_file(temp_dir.path(), "wip.txt", "uncommitted");
+
+        let result = git_checkout(temp_dir.path(), "feature", false);
+        assert!(matches!(result, Err(AppError::CommandError(_))));
+
+        // Untracked files survive a checkout, so forcing is safe here
+        let branch = git_checkout(temp_dir.path(), "feature", true).expect("Checkout failed");
+        assert_eq!(branch, "feature");
+    }
+
+    #[test]
+    fn test_git_checkout_rejects_file_name_without_branch() {
+        let temp_dir = create_temp_dir();
+        init_repo(temp_dir.path());
+        create_temp_file(temp_dir.path(), "README.md", "original");
+        git(temp_dir.path(), &["add", "README.md"]);
+        git(temp_dir.path(), &["commit", "-q", "-m", "readme"]);
+        create_temp_file(temp_dir.path(), "README.md", "modified");
+
+        let result = git_checkout(temp_dir.path(), "README.md", true);
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+
+        let content = fs::read_to_string(temp_dir.path().join("README.md")).unwrap();
+        assert_eq!(content, "modified");
+    }
+
+    #[test]
+    fn test_group_by_repo_shares_monorepo_root() {
+        let temp_dir = create_temp_dir();
+        let root = temp_dir.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        for name in ["web", "api"] {
+            fs::create_dir_all(root.join(name)).unwrap();
+            create_temp_file(&root.join(name), "package.json", r#"{"name": "app"}"#);
+        }
+
+        let projects = crate::detection::scan_directory(&root, 1).unwrap();
+        assert_eq!(projects.len(), 2);
+
+        let groups = group_by_repo(projects);
+        assert_eq!(groups.len(), 1);
+        let grouped = &groups[&root.to_string_lossy().to_string()];
+        let mut names: Vec<&str> = grouped.iter().map(|p| p.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["api", "web"]);
+    }
+
+    #[test]
+    fn test_parse_porcelain_v2() {
+        let output = [
+            "# branch.oid 1234567890abcdef1234567890abcdef12345678",
+            "# branch.head main",
+            "# branch.upstream origin/main",
+            "# branch.ab +2 -3",
+            "1 M. N... 100644 100644 100644 aaaaaaa bbbbbbb src/staged.ts",
+            "1 .M N... 100644 100644 100644 aaaaaaa aaaaaaa src/modified file.ts",
+            "1 MM N... 100644 100644 100644 aaaaaaa bbbbbbb src/both.ts",
+            "2 R. N... 100644 100644 100644 aaaaaaa aaaaaaa R100 src/new-name.ts",
+            "src/old-name.ts",
+            "u UU N... 100644 100644 100644 100644 aaaaaaa bbbbbbb ccccccc src/conflict.ts",
+            "? notes\ttab \"quoted\".txt",
+            "! dist/bundle.js",
+            "",
+        ]
+        .join("\0");
+
+        assert_eq!(
+            parse_porcelain_v2(&output),
+            GitStatus {
+                staged: vec![
+                    "src/staged.ts".to_string(),
+                    "src/both.ts".to_string(),
+                    "src/new-name.ts".to_string(),
+                ],
+                modified: vec![
+                    "src/modified file.ts".to_string(),
+                    "src/both.ts".to_string(),
+                    "src/conflict.ts".to_string(),
+                ],
+                untracked: vec!["notes\ttab \"quoted\".txt".to_string()],
+                ahead: 2,
+                behind: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_git_status_repository() {
+        let temp_dir = create_temp_dir();
+        init_repo(temp_dir.path());
+        create_temp_file(temp_dir.path(), "new.txt", "hello");
+
+        let status = git_status(temp_dir.path()).unwrap();
+        assert_eq!(status.untracked, vec!["new.txt".to_string()]);
+        assert!(status.staged.is_empty());
+    }
+
+    #[test]
+    fn test_git_status_rename_with_unusual_name() {
+        let temp_dir = create_temp_dir();
+        init_repo(temp_dir.path());
+        create_temp_file(temp_dir.path(), "old.txt", "hello");
+        git(temp_dir.path(), &["add", "old.txt"]);
+        git(temp_dir.path(), &["commit", "-q", "-m", "old"]);
+        git(temp_dir.path(), &["mv", "old.txt", "new \"name\".txt"]);
+
+        let status = git_status(temp_dir.path()).unwrap();
+        assert_eq!(status.staged, vec!["new \"name\".txt".to_string()]);
+        assert!(status.modified.is_empty());
+        assert!(status.untracked.is_empty());
+    }
+
+    #[test]
+    fn test_git_status_not_a_repository() {
+        let temp_dir = create_temp_dir();
+        if find_repo_root(temp_dir.path()).is_none() {
+            let result = git_status(temp_dir.path());
+            assert!(matches!(result, Err(AppError::NotFound(_))));
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::error::AppError;
+use crate::types::{HealthFactor, HealthScore};
+use std::fs;
+use std::path::Path;
+use std::time::SystemTime;
+
+// Scoring weights for each health factor (they add up to 100)
+const WEIGHT_DEPENDENCIES_INSTALLED: u32 = 25;
+const WEIGHT_LOCKFILE_FRESH: u32 = 15;
+const WEIGHT_ENGINE_SATISFIED: u32 = 15;
+const WEIGHT_GIT_CLEAN: u32 = 15;
+const WEIGHT_HAS_DEV_SCRIPT: u32 = 15;
+const WEIGHT_HAS_TESTS: u32 = 15;
+
+/// Lockfiles recognized when checking for staleness
+const LOCKFILES: &[&str] = &[
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "package-lock.json",
+    "bun.lockb",
+    "bun.lock",
+];
+
+/// Test script npm generates by default, which doesn't run anything
+const NPM_PLACEHOLDER_TEST: &str = "echo \"Error: no test specified\"";
+
+/// Raw signals gathered from a project, before scoring
+///
+/// `None` means the signal doesn't apply to the project and is left out of
+/// the score instead of counting as a failure.
+#[derive(Debug, Default)]
+pub struct HealthInputs {
+    pub dependencies_installed: Option<bool>,
+    pub lockfile_fresh: Option<bool>,
+    pub engine_satisfied: Option<bool>,
+    pub git_clean: Option<bool>,
+    pub has_dev_script: bool,
+    pub has_tests: bool,
+}
+
+/// Computes the 0-100 health score from the gathered inputs
+///
+/// Factors that don't apply are excluded and the remaining weights are
+/// scaled so a project passing every applicable factor still scores 100.
+pub fn compute_health(inputs: &HealthInputs) -> HealthScore {
+    let factors = vec![
+        HealthFactor {
+            name: "dependencies_installed".to_string(),
+            weight: WEIGHT_DEPENDENCIES_INSTALLED,
+            passed: inputs.dependencies_installed,
+        },
+        HealthFactor {
+            name: "lockfile_fresh".to_string(),
+            weight: WEIGHT_LOCKFILE_FRESH,
+            passed: inputs.lockfile_fresh,
+        },
+        HealthFactor {
+            name: "engine_satisfied".to_string(),
+            weight: WEIGHT_ENGINE_SATISFIED,
+            passed: inputs.engine_satisfied,
+        },
+        HealthFactor {
+            name: "git_clean".to_string(),
+            weight: WEIGHT_GIT_CLEAN,
+            passed: inputs.git_clean,
+        },
+        HealthFactor {
+            name: "has_dev_script".to_string(),
+            weight: WEIGHT_HAS_DEV_SCRIPT,
+            passed: Some(inputs.has_dev_script),
+        },
+        HealthFactor {
+            name: "has_tests".to_string(),
+            weight: WEIGHT_HAS_TESTS,
+            passed: Some(inputs.has_tests),
+        },
+    ];
+
+    let applicable: u32 = factors
+        .iter()
+        .filter(|factor| factor.passed.is_some())
+        .map(|factor| factor.weight)
+        .sum();
+    let earned: u32 = factors
+        .iter()
+        .filter(|factor| factor.passed == Some(true))
+        .map(|factor| factor.weight)
+        .sum();
+
+    // Round to the nearest integer; no applicable factors means nothing to score
+    let score = (earned * 100 + applicable / 2)
+        .checked_div(applicable)
+        .unwrap_or(0) as u8;
+
+    HealthScore { score, factors }
+}
+
+/// Checks whether a Node.js version satisfies a package.json `engines.node` range
+///
+/// This is a best-effort check on the major version of the first comparator
+/// (`>=18`, `^20.1.0`, `~18`, `18.x`, `20`). Returns None for ranges it doesn't
+/// understand so they aren't reported as failures.
+fn engine_satisfied(range: &str, version: &str) -> Option<bool> {
+    fn leading_major(s: &str) -> Option<u32> {
+        let digits: String = s.chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse().ok()
+    }
+
+    let current_major = leading_major(version.trim().trim_start_matches('v'))?;
+    let comparator = range.split("||").next()?.split_whitespace().next()?;
+
+    if let Some(rest) = comparator.strip_prefix(">=") {
+        Some(current_major >= leading_major(rest.trim_start_matches('v'))?)
+    } else if let Some(rest) = comparator.strip_prefix('>') {
+        Some(current_major > leading_major(rest.trim_start_matches('v'))?)
+    } else {
+        let rest = comparator.trim_start_matches(['^', '~', '=', 'v']);
+        Some(current_major == leading_major(rest)?)
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Gathers the health signals for a project directory
+fn gather_inputs(path: &Path) -> HealthInputs {
+    let package_json_path = path.join("package.json");
+    let package_json = fs::read_to_string(&package_json_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok());
+
+    let mut inputs = HealthInputs {
+        git_clean: crate::git::is_dirty(path).map(|dirty| !dirty),
+        ..Default::default()
+    };
+
+    // Deno projects don't have an install step or package.json scripts
+    let package_json = match package_json {
+        Some(package_json) => package_json,
+        None => {
+            inputs.has_dev_script =
+                path.join("deno.json").exists() || path.join("deno.jsonc").exists();
+            return inputs;
+        }
+    };
+
+    inputs.dependencies_installed = Some(path.join("node_modules").is_dir());
+
+    // A lockfile older than package.json means dependencies changed without reinstalling
+    let package_json_modified = modified_time(&package_json_path);
+    let lockfile_modified = LOCKFILES
+        .iter()
+        .find_map(|lockfile| modified_time(&path.join(lockfile)));
+    inputs.lockfile_fresh = Some(match (lockfile_modified, package_json_modified) {
+        (Some(lockfile), Some(package_json)) => lockfile >= package_json,
+        _ => false,
+    });
+
+    inputs.engine_satisfied = package_json
+        .get("engines")
+        .and_then(|engines| engines.get("node"))
+        .and_then(|range| range.as_str())
+        .and_then(|range| {
+            let version = crate::project_info::get_runtime_version("Node.js", &path.to_path_buf())?;
+            engine_satisfied(range, &version)
+        });
+
+    if let Some(scripts) = package_json.get("scripts").and_then(|s| s.as_object()) {
+        inputs.has_dev_script = scripts.contains_key("dev") || scripts.contains_key("start");
+        inputs.has_tests = scripts
+            .get("test")
+            .and_then(|test| test.as_str())
+            .is_some_and(|test| !test.contains(NPM_PLACEHOLDER_TEST));
+    }
+
+    inputs
+}
+
+/// Computes the health score for a project directory
+pub fn project_health(path: &Path) -> Result<HealthScore, AppError> {
+    Ok(compute_health(&gather_inputs(path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_temp_dir() -> TempDir {
+        tempfile::tempdir().expect("Failed to create temp directory")
+    }
+
+    #[test]
+    fn test_compute_health_all_passing() {
+        let inputs = HealthInputs {
+            dependencies_installed: Some(true),
+            lockfile_fresh: Some(true),
+            engine_satisfied: Some(true),
+            git_clean: Some(true),
+            has_dev_script: true,
+            has_tests: true,
+        };
+
+        let health = compute_health(&inputs);
+        assert_eq!(health.score, 100);
+        assert_eq!(health.factors.len(), 6);
+    }
+
+    #[test]
+    fn test_compute_health_nothing_passing() {
+        let inputs = HealthInputs {
+            dependencies_installed: Some(false),
+            lockfile_fresh: Some(false),
+            engine_satisfied: Some(false),
+            git_clean: Some(false),
+            has_dev_script: false,
+            has_tests: false,
+        };
+
+        assert_eq!(compute_health(&inputs).score, 0);
+    }
+
+    #[test]
+    fn test_compute_health_partial() {
+        // Missing dependencies (25) and tests (15) out of 100
+        let inputs = HealthInputs {
+            dependencies_installed: Some(false),
+            lockfile_fresh: Some(true),
+            engine_satisfied: Some(true),
+            git_clean: Some(true),
+            has_dev_script: true,
+            has_tests: false,
+        };
+
+        assert_eq!(compute_health(&inputs).score, 60);
+    }
+
+    #[test]
+    fn test_compute_health_skips_inapplicable_factors() {
+        // Only dev script (15) and tests (15) apply; one of two passes
+        let inputs = HealthInputs {
+            has_dev_script: true,
+            has_tests: false,
+            ..Default::default()
+        };
+
+        assert_eq!(compute_health(&inputs).score, 50);
+    }
+
+    #[test]
+    fn test_engine_satisfied() {
+        assert_eq!(engine_satisfied(">=18", "v20.11.0"), Some(true));
+        assert_eq!(engine_satisfied(">=18.0.0", "v16.20.0"), Some(false));
+        assert_eq!(engine_satisfied("^20.1.0", "v20.11.0"), Some(true));
+        assert_eq!(engine_satisfied("18.x", "v20.11.0"), Some(false));
+        assert_eq!(engine_satisfied(">=18 <21", "v22.0.0"), Some(true));
+        assert_eq!(engine_satisfied("lts/*", "v20.11.0"), None);
+    }
+
+    #[test]
+    fn test_gather_inputs_detects_scripts() {
+        let temp_dir = create_temp_dir();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"scripts": {"dev": "vite", "test": "echo \"Error: no test specified\" && exit 1"}}"#,
+        )
+        .expect("Failed to write package.json");
+
+        let inputs = gather_inputs(temp_dir.path());
+        assert!(inputs.has_dev_script);
+        assert!(
+            !inputs.has_tests,
+            "npm's placeholder test script shouldn't count"
+        );
+        assert_eq!(inputs.dependencies_installed, Some(false));
+        assert_eq!(inputs.lockfile_fresh, Some(false));
+    }
+}
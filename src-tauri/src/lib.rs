@@ -1,37 +1,85 @@
 mod detection;
 pub mod error;
+mod git;
+mod health;
+mod lockfile;
+mod log_history;
 mod menu;
 mod port;
 mod process;
 mod process_logs;
 mod project_info;
 mod quick_actions;
+mod scripts;
+mod task_runner;
 mod types;
 pub mod validation;
+mod watcher;
+mod workspace;
 
 #[tauri::command]
-fn scan_directory(path: String) -> Result<Vec<types::Project>, error::AppError> {
+async fn scan_directory(
+    path: String,
+    max_depth: Option<usize>,
+) -> Result<Vec<types::Project>, error::AppError> {
     // Validate path before processing
     let validated_path = validation::validate_directory_path(&path)?;
     
     // Pass PathBuf directly to maintain type safety
-    detection::scan_directory(&validated_path)
+    detection::scan_directory(&validated_path, max_depth.unwrap_or(1))
 }
 
 #[tauri::command]
-fn kill_process_tree(pid: u32) -> Result<(), error::AppError> {
+fn cancel_scan() {
+    // The scan commands run off the main thread, so this can land while one is in progress
+    detection::cancel_scan()
+}
+
+#[tauri::command]
+async fn scan_grouped_by_repo(
+    path: String,
+    max_depth: Option<usize>,
+) -> Result<std::collections::HashMap<String, Vec<types::Project>>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&path)?;
+
+    let projects = detection::scan_directory(&validated_path, max_depth.unwrap_or(1))?;
+    Ok(git::group_by_repo(projects))
+}
+
+#[tauri::command]
+async fn find_duplicate_projects(
+    path: String,
+    max_depth: Option<usize>,
+) -> Result<Vec<Vec<String>>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&path)?;
+
+    // Fingerprints only need package.json, so skip enrichment and the active scan slot
+    let projects = detection::detect_projects(&validated_path, max_depth.unwrap_or(1))?;
+    Ok(detection::find_duplicate_projects(&projects))
+}
+
+#[tauri::command]
+fn kill_process_tree(pid: u32, max_depth: Option<usize>) -> Result<Vec<u32>, error::AppError> {
     // Validate PID before processing
     let validated_pid = validation::validate_pid(pid)?;
     
-    process::kill_process_tree(validated_pid)
+    process::kill_process_tree(
+        validated_pid,
+        max_depth.unwrap_or(process::DEFAULT_KILL_DEPTH),
+    )
 }
 
 #[tauri::command]
-fn detect_port_by_pid(pid: u32) -> Result<Option<u16>, error::AppError> {
+fn detect_port_by_pid(pid: u32, max_depth: Option<usize>) -> Result<Option<u16>, error::AppError> {
     // Validate PID before processing
     let validated_pid = validation::validate_pid(pid)?;
     
-    process::detect_port_by_pid(validated_pid)
+    process::detect_port_by_pid(
+        validated_pid,
+        max_depth.unwrap_or(process::DEFAULT_PORT_SEARCH_DEPTH),
+    )
 }
 
 #[tauri::command]
@@ -61,12 +109,360 @@ fn open_in_file_manager(path: String) -> Result<(), error::AppError> {
     quick_actions::open_in_file_manager(&validated_path)
 }
 
+#[tauri::command]
+fn open_in_browser(port: u16, uses_https: Option<bool>) -> Result<(), error::AppError> {
+    // Port 0 means "any port" when binding and is never where a server listens
+    if port == 0 {
+        return Err(error::AppError::CommandError(
+            "Invalid port: 0".to_string(),
+        ));
+    }
+
+    quick_actions::open_in_browser(port, uses_https.unwrap_or(false))
+}
+
 #[tauri::command]
 fn validate_directory_path_command(path: String) -> Result<(), error::AppError> {
     // Validate that the path exists and is a directory
     validation::validate_directory_path(&path).map(|_| ())
 }
 
+#[tauri::command]
+fn resolve_port_conflicts(paths: Vec<String>) -> Result<Vec<(String, u16)>, error::AppError> {
+    // Validate every path before processing
+    let validated_paths = paths
+        .iter()
+        .map(|path| validation::validate_directory_path(path))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(port::resolve_port_conflicts(&validated_paths))
+}
+
+#[tauri::command]
+fn port_warnings(port: u16) -> Vec<String> {
+    port::port_warnings(port)
+}
+
+#[tauri::command]
+fn open_issues(project_path: String) -> Result<(), error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    git::open_issues(&validated_path)
+}
+
+#[tauri::command]
+fn open_remotes(paths: Vec<String>) -> types::OpenRemotesResult {
+    // Each path is validated as it's opened so one bad path doesn't fail the batch
+    git::open_remotes(&paths)
+}
+
+#[tauri::command]
+fn project_health(project_path: String) -> Result<types::HealthScore, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    health::project_health(&validated_path)
+}
+
+#[tauri::command]
+fn duplicate_dependencies(
+    project_path: String,
+) -> Result<Vec<(String, Vec<String>)>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    lockfile::duplicate_dependencies(&validated_path)
+}
+
+#[tauri::command]
+fn export_logs(project_path: String, dest_path: String) -> Result<(), error::AppError> {
+    // Validate paths before processing
+    validation::validate_directory_path(&project_path)?;
+    let destination = validation::validate_output_file_path(&dest_path)?;
+
+    // Log history is keyed by the project path as the frontend passed it
+    log_history::export_logs(&project_path, &destination)
+}
+
+#[tauri::command]
+fn detect_proxy_targets(project_path: String) -> Result<Vec<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(port::detect_proxy_targets(&validated_path))
+}
+
+#[tauri::command]
+fn documented_commands(project_path: String) -> Result<Vec<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::documented_commands(&validated_path))
+}
+
+#[tauri::command]
+async fn prewarm_shell() -> Result<(), error::AppError> {
+    // Sourcing the user's shell config can take seconds, so keep it off the main thread
+    process_logs::prewarm_shell()
+}
+
+#[tauri::command]
+fn framework_candidates(project_path: String) -> Result<Vec<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(detection::framework_candidates(&validated_path))
+}
+
+#[tauri::command]
+fn ready_pattern_for(framework: String) -> Option<String> {
+    port::ready_pattern_for(&framework)
+}
+
+#[tauri::command]
+fn devcontainer_info(project_path: String) -> Result<types::DevcontainerInfo, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    project_info::devcontainer_info(&validated_path)
+}
+
+#[tauri::command]
+fn detect_public_dir(project_path: String) -> Result<Option<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::detect_public_dir(&validated_path))
+}
+
+#[tauri::command]
+fn detect_render_mode(project_path: String) -> Result<Option<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::detect_render_mode(&validated_path))
+}
+
+#[tauri::command]
+fn detect_size_budget(
+    project_path: String,
+) -> Result<Option<Vec<types::BudgetEntry>>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    project_info::detect_size_budget(&validated_path)
+}
+
+#[tauri::command]
+fn detect_import_aliases(
+    project_path: String,
+) -> Result<std::collections::HashMap<String, String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    project_info::detect_import_aliases(&validated_path)
+}
+
+#[tauri::command]
+fn detect_task_runner(project_path: String) -> Result<Option<types::TaskRunner>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(task_runner::detect_task_runner(&validated_path))
+}
+
+#[tauri::command]
+fn build_is_stale(project_path: String) -> Result<Option<bool>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::build_is_stale(&validated_path))
+}
+
+#[tauri::command]
+fn detect_server_ports(project_path: String) -> Result<Vec<(String, u16)>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(port::detect_server_ports(&validated_path))
+}
+
+#[tauri::command]
+fn required_env_vars(project_path: String) -> Result<Vec<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::required_env_vars(&validated_path))
+}
+
+#[tauri::command]
+fn reconcile_port(project_path: String, running_pid: u32) -> Result<Option<u16>, error::AppError> {
+    // Validate path and PID before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+    let validated_pid = validation::validate_pid(running_pid)?;
+
+    process::reconcile_port(&validated_path, validated_pid)
+}
+
+#[tauri::command]
+fn detect_migration_command(project_path: String) -> Result<Option<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(scripts::detect_migration_command(&validated_path))
+}
+
+#[tauri::command]
+fn key_directories(
+    project_path: String,
+) -> Result<std::collections::HashMap<String, String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::key_directories(&validated_path))
+}
+
+#[tauri::command]
+fn requires_database(project_path: String) -> Result<Option<bool>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::requires_database(&validated_path))
+}
+
+#[tauri::command]
+fn detect_api_framework(project_path: String) -> Result<Option<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::get_api_framework(&validated_path))
+}
+
+#[tauri::command]
+fn detect_sourcemaps(project_path: String) -> Result<Option<bool>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    project_info::detect_sourcemaps(&validated_path)
+}
+
+#[tauri::command]
+fn workspace_graph(root_path: String) -> Result<Vec<(String, Vec<String>)>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&root_path)?;
+
+    workspace::workspace_graph(&validated_path)
+}
+
+#[tauri::command]
+fn detect_test_globs(project_path: String) -> Result<Vec<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::detect_test_globs(&validated_path))
+}
+
+#[tauri::command]
+fn find_bundle_report(project_path: String) -> Result<Option<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::find_bundle_report(&validated_path))
+}
+
+#[tauri::command]
+fn build_output_size(project_path: String) -> Result<Option<u64>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(project_info::build_output_size(&validated_path))
+}
+
+#[tauri::command]
+fn detect_preview_command(project_path: String) -> Result<Option<String>, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    Ok(scripts::select_preview_command(&validated_path)
+        .map(|(command, args)| format!("{} {}", command, args.join(" "))))
+}
+
+#[tauri::command]
+fn discover_orphaned_servers(
+    paths: Vec<String>,
+) -> Result<Vec<(String, u16, u32)>, error::AppError> {
+    // Validate every path before probing
+    let validated_paths = paths
+        .iter()
+        .map(|path| validation::validate_directory_path(path))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(process::discover_orphaned_servers(&validated_paths))
+}
+
+#[tauri::command]
+fn adopt_process(
+    project_path: String,
+    pid: u32,
+) -> Result<types::RunningProcess, error::AppError> {
+    // Validate path and PID before processing
+    validation::validate_directory_path(&project_path)?;
+    let validated_pid = validation::validate_pid(pid)?;
+
+    // The registry is keyed by the project path as the frontend passed it
+    process::adopt_process(&project_path, validated_pid)
+}
+
+#[tauri::command]
+fn is_process_alive(pid: u32) -> Result<bool, error::AppError> {
+    // Validate PID before processing
+    let validated_pid = validation::validate_pid(pid)?;
+
+    Ok(process::process_exists(validated_pid))
+}
+
+#[tauri::command]
+fn list_running_processes() -> Vec<types::RunningProcess> {
+    process::list_running_processes()
+}
+
+#[tauri::command]
+fn check_disk_space(project_path: String) -> Result<types::DiskInfo, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    project_info::check_disk_space(&validated_path)
+}
+
+#[tauri::command]
+fn git_branches(project_path: String) -> Result<types::GitBranches, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    git::git_branches(&validated_path)
+}
+
+#[tauri::command]
+fn git_checkout(
+    project_path: String,
+    branch: String,
+    force: Option<bool>,
+) -> Result<String, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    git::git_checkout(&validated_path, &branch, force.unwrap_or(false))
+}
+
+#[tauri::command]
+fn git_status(project_path: String) -> Result<types::GitStatus, error::AppError> {
+    // Validate path before processing
+    let validated_path = validation::validate_directory_path(&project_path)?;
+
+    git::git_status(&validated_path)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -76,13 +472,68 @@ pub fn run() {
         .plugin(tauri_plugin_fs::init())
         .invoke_handler(tauri::generate_handler![
             scan_directory,
+            cancel_scan,
+            scan_grouped_by_repo,
             kill_process_tree,
             detect_port_by_pid,
             open_in_editor,
             open_in_terminal,
             open_in_file_manager,
+            open_in_browser,
             validate_directory_path_command,
-            process_logs::spawn_process_with_logs
+            resolve_port_conflicts,
+            port_warnings,
+            open_issues,
+            open_remotes,
+            project_health,
+            watcher::watch_project,
+            watcher::stop_watch_project,
+            git_branches,
+            git_checkout,
+            git_status,
+            duplicate_dependencies,
+            export_logs,
+            detect_proxy_targets,
+            documented_commands,
+            prewarm_shell,
+            framework_candidates,
+            ready_pattern_for,
+            devcontainer_info,
+            detect_public_dir,
+            detect_render_mode,
+            detect_size_budget,
+            detect_import_aliases,
+            detect_task_runner,
+            build_is_stale,
+            detect_server_ports,
+            required_env_vars,
+            reconcile_port,
+            detect_migration_command,
+            key_directories,
+            requires_database,
+            detect_api_framework,
+            detect_sourcemaps,
+            workspace_graph,
+            detect_test_globs,
+            find_bundle_report,
+            build_output_size,
+            find_duplicate_projects,
+            detect_preview_command,
+            discover_orphaned_servers,
+            adopt_process,
+            list_running_processes,
+            is_process_alive,
+            check_disk_space,
+            scripts::format_project,
+            scripts::run_migrations,
+            scripts::audit_summary,
+            scripts::run_script,
+            scripts::preview_project,
+            scripts::install_dependencies,
+            process_logs::spawn_process_with_logs,
+            process_logs::restart_process,
+            process_logs::pause_log_stream,
+            process_logs::resume_log_stream
         ])
         .setup(|app| {
             menu::setup_menu(app)?;
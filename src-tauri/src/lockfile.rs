@@ -0,0 +1,258 @@
+use crate::error::AppError;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// Resolved versions per package name
+type ResolvedVersions = BTreeMap<String, BTreeSet<String>>;
+
+/// Collects resolved versions from a package-lock.json
+///
+/// Lockfile v2/v3 lists every install location under `packages`
+/// (`node_modules/a/node_modules/foo`); v1 only has the nested `dependencies` tree.
+fn parse_package_lock(content: &str) -> Result<ResolvedVersions, AppError> {
+    fn walk_v1(dependencies: &serde_json::Value, versions: &mut ResolvedVersions) {
+        let Some(dependencies) = dependencies.as_object() else {
+            return;
+        };
+
+        for (name, info) in dependencies {
+            if let Some(version) = info.get("version").and_then(|v| v.as_str()) {
+                versions
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(version.to_string());
+            }
+            if let Some(nested) = info.get("dependencies") {
+                walk_v1(nested, versions);
+            }
+        }
+    }
+
+    let lock: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| AppError::ParseError(format!("Invalid package-lock.json: {}", e)))?;
+
+    let mut versions = ResolvedVersions::new();
+
+    if let Some(packages) = lock.get("packages").and_then(|p| p.as_object()) {
+        for (location, info) in packages {
+            // The "" entry is the project itself
+            let Some((_, name)) = location.rsplit_once("node_modules/") else {
+                continue;
+            };
+            if let Some(version) = info.get("version").and_then(|v| v.as_str()) {
+                versions
+                    .entry(name.to_string())
+                    .or_default()
+                    .insert(version.to_string());
+            }
+        }
+    } else if let Some(dependencies) = lock.get("dependencies") {
+        walk_v1(dependencies, &mut versions);
+    }
+
+    Ok(versions)
+}
+
+/// Splits a pnpm package key into name and version
+///
+/// Handles `/foo@1.0.0` (v6), `foo@1.0.0` (v9), `/foo/1.0.0` (v5) and scoped
+/// names, dropping peer dependency suffixes like `(react@18.2.0)` or `_react@18.2.0`.
+fn parse_pnpm_package_key(key: &str) -> Option<(String, String)> {
+    let key = key.trim_matches(['\'', '"']).trim_start_matches('/');
+    let key = key.split('(').next()?;
+
+    let (scope, rest) = match key.strip_prefix('@') {
+        Some(scoped) => {
+            let (scope, rest) = scoped.split_once('/')?;
+            (Some(scope), rest)
+        }
+        None => (None, key),
+    };
+
+    let (name, version) = match rest.split_once('/') {
+        // v5 keys separate the version with a slash and append peers after '_'
+        Some((name, version)) => (name, version.split('_').next()?),
+        None => rest.split_once('@')?,
+    };
+    let name = match scope {
+        Some(scope) => format!("@{}/{}", scope, name),
+        None => name.to_string(),
+    };
+
+    if name.is_empty() || version.is_empty() {
+        return None;
+    }
+
+    Some((name, version.to_string()))
+}
+
+/// Collects resolved versions from the `packages` section of a pnpm-lock.yaml
+fn parse_pnpm_lock(content: &str) -> ResolvedVersions {
+    let mut versions = ResolvedVersions::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        if !line.starts_with(' ') && !line.trim().is_empty() {
+            in_packages = line.trim_end() == "packages:";
+            continue;
+        }
+        if !in_packages {
+            continue;
+        }
+
+        // Package keys are the only entries indented by exactly two spaces
+        let Some(key) = line.strip_prefix("  ") else {
+            continue;
+        };
+        if key.starts_with(' ') {
+            continue;
+        }
+        let Some(key) = key.trim_end().strip_suffix(':') else {
+            continue;
+        };
+
+        if let Some((name, version)) = parse_pnpm_package_key(key) {
+            versions.entry(name).or_default().insert(version);
+        }
+    }
+
+    versions
+}
+
+/// Finds dependencies resolved at more than one version in the project's lockfile
+///
+/// Supports package-lock.json and pnpm-lock.yaml. Results are sorted by name,
+/// with each dependency's versions sorted as well.
+pub fn duplicate_dependencies(path: &Path) -> Result<Vec<(String, Vec<String>)>, AppError> {
+    let package_lock = path.join("package-lock.json");
+    let pnpm_lock = path.join("pnpm-lock.yaml");
+
+    let versions = if package_lock.exists() {
+        parse_package_lock(&fs::read_to_string(package_lock)?)?
+    } else if pnpm_lock.exists() {
+        parse_pnpm_lock(&fs::read_to_string(pnpm_lock)?)
+    } else {
+        return Err(AppError::NotFound(
+            "No supported lockfile found (package-lock.json or pnpm-lock.yaml)".to_string(),
+        ));
+    };
+
+    Ok(versions
+        .into_iter()
+        .filter(|(_, versions)| versions.len() > 1)
+        .map(|(name, versions)| (name, versions.into_iter().collect()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_temp_dir() -> TempDir {
+        tempfile::tempdir().expect("Failed to create temp directory")
+    }
+
+    #[test]
+    fn test_duplicate_dependencies_package_lock() {
+        let temp_dir = create_temp_dir();
+        fs::write(
+            temp_dir.path().join("package-lock.json"),
+            r#"{
+                "lockfileVersion": 3,
+                "packages": {
+                    "": { "name": "app" },
+                    "node_modules/lodash": { "version": "4.17.21" },
+                    "node_modules/react": { "version": "18.2.0" },
+                    "node_modules/legacy/node_modules/lodash": { "version": "3.10.1" },
+                    "node_modules/other/node_modules/react": { "version": "18.2.0" }
+                }
+            }"#,
+        )
+        .expect("Failed to write package-lock.json");
+
+        let duplicates = duplicate_dependencies(temp_dir.path()).unwrap();
+        assert_eq!(
+            duplicates,
+            vec![(
+                "lodash".to_string(),
+                vec!["3.10.1".to_string(), "4.17.21".to_string()]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_dependencies_package_lock_v1() {
+        let versions = parse_package_lock(
+            r#"{
+                "lockfileVersion": 1,
+                "dependencies": {
+                    "debug": {
+                        "version": "4.3.4",
+                        "dependencies": { "ms": { "version": "2.1.2" } }
+                    },
+                    "ms": { "version": "2.1.3" }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(versions["ms"].len(), 2);
+        assert_eq!(versions["debug"].len(), 1);
+    }
+
+    #[test]
+    fn test_duplicate_dependencies_pnpm_lock() {
+        let temp_dir = create_temp_dir();
+        fs::write(
+            temp_dir.path().join("pnpm-lock.yaml"),
+            "lockfileVersion: '6.0'\n\
+             \n\
+             dependencies:\n\
+             \x20 react:\n\
+             \x20   specifier: ^18.2.0\n\
+             \x20   version: 18.2.0\n\
+             \n\
+             packages:\n\
+             \n\
+             \x20 /@types/node@18.19.0:\n\
+             \x20   resolution: {integrity: sha512-a}\n\
+             \x20 /@types/node@20.11.0:\n\
+             \x20   resolution: {integrity: sha512-b}\n\
+             \x20 /react-dom@18.2.0(react@18.2.0):\n\
+             \x20   resolution: {integrity: sha512-c}\n\
+             \x20 /react@18.2.0:\n\
+             \x20   resolution: {integrity: sha512-d}\n",
+        )
+        .expect("Failed to write pnpm-lock.yaml");
+
+        let duplicates = duplicate_dependencies(temp_dir.path()).unwrap();
+        assert_eq!(
+            duplicates,
+            vec![(
+                "@types/node".to_string(),
+                vec!["18.19.0".to_string(), "20.11.0".to_string()]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_pnpm_package_key_formats() {
+        let expected = Some(("@scope/pkg".to_string(), "1.2.3".to_string()));
+        assert_eq!(parse_pnpm_package_key("/@scope/pkg@1.2.3"), expected);
+        assert_eq!(parse_pnpm_package_key("'@scope/pkg@1.2.3'"), expected);
+        assert_eq!(parse_pnpm_package_key("/@scope/pkg/1.2.3"), expected);
+        assert_eq!(
+            parse_pnpm_package_key("/@scope/pkg/1.2.3_react@18.2.0"),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_duplicate_dependencies_no_lockfile() {
+        let temp_dir = create_temp_dir();
+        let result = duplicate_dependencies(temp_dir.path());
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
@@ -0,0 +1,197 @@
+use crate::error::AppError;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum number of log lines kept per project; older lines are dropped first
+const MAX_LINES_PER_PROJECT: usize = 5000;
+
+// Recent process output keyed by project path
+static LOG_HISTORY: OnceLock<Mutex<HashMap<String, VecDeque<LogLine>>>> = OnceLock::new();
+
+fn get_log_history() -> &'static Mutex<HashMap<String, VecDeque<LogLine>>> {
+    LOG_HISTORY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+impl LogStream {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LogStream::Stdout => "stdout",
+            LogStream::Stderr => "stderr",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// Unix timestamp in milliseconds when the line was read
+    pub timestamp: u128,
+    pub stream: LogStream,
+    pub content: String,
+}
+
+impl LogLine {
+    fn format(&self) -> String {
+        format!(
+            "[{}] [{}] {}",
+            self.timestamp,
+            self.stream.as_str(),
+            self.content
+        )
+    }
+}
+
+/// Appends a line to the project's history, evicting the oldest line when full
+///
+/// `read_at` is when the line was read from the pipe. Returns the line's
+/// timestamp so the live event can carry the same value.
+pub fn record(project_path: &str, stream: LogStream, content: &str, read_at: SystemTime) -> u128 {
+    let timestamp = read_at
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+
+    // A poisoned lock only means another reader thread panicked; keep logging
+    let mut history = get_log_history()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let lines = history.entry(project_path.to_string()).or_default();
+    if lines.len() >= MAX_LINES_PER_PROJECT {
+        lines.pop_front();
+    }
+    lines.push_back(LogLine {
+        timestamp,
+        stream,
+        content: content.to_string(),
+    });
+    timestamp
+}
+
+/// Returns a copy of the buffered lines for a project, oldest first
+pub fn get_history(project_path: &str) -> Option<Vec<LogLine>> {
+    let history = get_log_history()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    history
+        .get(project_path)
+        .map(|lines| lines.iter().cloned().collect())
+}
+
+/// Writes the buffered log lines for a project to a file
+///
+/// Each line is written as `[<unix millis>] [stdout|stderr] <content>`, in the
+/// order it was received.
+pub fn export_logs(project_path: &str, destination: &Path) -> Result<(), AppError> {
+    let lines = get_history(project_path)
+        .filter(|lines| !lines.is_empty())
+        .ok_or_else(|| {
+            AppError::NotFound(format!("No log history for project: {}", project_path))
+        })?;
+
+    let mut output = lines
+        .iter()
+        .map(LogLine::format)
+        .collect::<Vec<_>>()
+        .join("\n");
+    output.push('\n');
+
+    fs::write(destination, output)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_logs_writes_lines_in_order() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let project_path = "/test/log-history/export-order";
+        let destination = temp_dir.path().join("logs.txt");
+
+        record(
+            project_path,
+            LogStream::Stdout,
+            "starting dev server",
+            SystemTime::now(),
+        );
+        record(
+            project_path,
+            LogStream::Stderr,
+            "warning: deprecated option",
+            SystemTime::now(),
+        );
+        record(
+            project_path,
+            LogStream::Stdout,
+            "ready on http://localhost:3000",
+            SystemTime::now(),
+        );
+
+        export_logs(project_path, &destination).unwrap();
+
+        let content = fs::read_to_string(&destination).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].ends_with("[stdout] starting dev server"));
+        assert!(lines[1].ends_with("[stderr] warning: deprecated option"));
+        assert!(lines[2].ends_with("[stdout] ready on http://localhost:3000"));
+        assert!(lines[0].starts_with('['));
+    }
+
+    #[test]
+    fn test_record_returns_recorded_timestamp() {
+        let project_path = "/test/log-history/timestamp";
+
+        let read_at = UNIX_EPOCH + std::time::Duration::from_millis(1_700_000_000_123);
+        let first = record(project_path, LogStream::Stdout, "compiling", read_at);
+        let second = record(
+            project_path,
+            LogStream::Stdout,
+            "compiled",
+            SystemTime::now(),
+        );
+
+        let history = get_history(project_path).unwrap();
+        assert_eq!(first, 1_700_000_000_123);
+        assert_eq!(history[0].timestamp, first);
+        assert_eq!(history[1].timestamp, second);
+        assert!(second > first);
+    }
+
+    #[test]
+    fn test_record_evicts_oldest_lines() {
+        let project_path = "/test/log-history/eviction";
+
+        for i in 0..MAX_LINES_PER_PROJECT + 2 {
+            record(
+                project_path,
+                LogStream::Stdout,
+                &format!("line {}", i),
+                SystemTime::now(),
+            );
+        }
+
+        let history = get_history(project_path).unwrap();
+        assert_eq!(history.len(), MAX_LINES_PER_PROJECT);
+        assert_eq!(history[0].content, "line 2");
+    }
+
+    #[test]
+    fn test_export_logs_without_history() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let result = export_logs(
+            "/test/log-history/missing",
+            &temp_dir.path().join("logs.txt"),
+        );
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+}
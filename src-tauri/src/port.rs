@@ -1,17 +1,50 @@
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
 
 pub fn detect_port(path: &PathBuf) -> Option<u16> {
+    // Multi-server projects report their primary (web) server's port
+    if let Some((_, port)) = detect_server_ports(path).first() {
+        return Some(*port);
+    }
+
     // Detect specific framework and its default port
     let framework = crate::detection::detect_framework_from_path(path);
 
-    // Try to read port from framework configuration files
-    if let Some(port) = detect_port_from_config(path, &framework) {
+    // Try to read port from framework configuration files and package.json scripts
+    let config_port = detect_port_from_config(path, &framework);
+    let script_port = detect_port_from_package_json(path);
+
+    // Vite's --port flag overrides server.port, unless strictPort pins the configured port
+    let script_overrides_config =
+        matches!(framework, "vite" | "remix" | "qwik") && !is_vite_strict_port(path);
+    let port = if script_overrides_config {
+        script_port.or(config_port)
+    } else {
+        config_port.or(script_port)
+    };
+    if port.is_some() {
+        return port;
+    }
+
+    // Heroku-style projects declare their start command in a Procfile instead
+    if let Some(port) = detect_port_from_procfile(path) {
+        return Some(port);
+    }
+
+    // A --port flag in a script overrides PORT, but PORT beats the default
+    if let Some(port) = detect_port_from_env(path) {
+        return Some(port);
+    }
+
+    // Projects run via `docker compose up` are reached on the mapped host port
+    if let Some(port) = detect_port_from_compose(path) {
         return Some(port);
     }
 
-    // Try to read port from package.json scripts
-    if let Some(port) = detect_port_from_package_json(path) {
+    // Plain Node servers often hardcode the port in their entry file
+    if let Some(port) = detect_port_from_source(path) {
         return Some(port);
     }
 
@@ -23,6 +56,11 @@ pub fn get_default_port(framework: &str) -> Option<u16> {
     match framework {
         "astro" => Some(4321),
         "nextjs" => Some(3000),
+        "remix" => Some(3000),
+        "solidstart" => Some(3000),
+        "angular" => Some(4200),
+        "gatsby" => Some(8000),
+        "qwik" => Some(5173),
         "vite" => Some(5173),
         "react" => Some(3000),
         "sveltekit" => Some(5173),
@@ -31,11 +69,112 @@ pub fn get_default_port(framework: &str) -> Option<u16> {
     }
 }
 
+/// Log fragments a framework's dev server prints once it accepts requests
+fn get_ready_markers(framework: &str) -> &'static [&'static str] {
+    match framework {
+        // Vite (and SvelteKit, which runs on it) and Nuxt print `Local: http://...`
+        "vite" | "sveltekit" | "qwik" | "nuxt" => &["Local:"],
+        "astro" => &["ready in"],
+        "nextjs" => &["Ready in", "ready - started server"],
+        "react" => &["Compiled successfully", "You can now view"],
+        _ => &[],
+    }
+}
+
+/// Escapes regex metacharacters so a literal can be embedded in a pattern
+fn escape_regex(literal: &str) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+    for c in literal.chars() {
+        if "\\.^$|?*+()[]{}".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Returns a regex matching the framework's "server ready" log line
+///
+/// The pattern is an alternation of literal fragments, so it matches exactly
+/// the lines `is_ready_line` accepts. Returns None for frameworks without a
+/// known ready banner.
+pub fn ready_pattern_for(framework: &str) -> Option<String> {
+    let markers = get_ready_markers(framework);
+    if markers.is_empty() {
+        return None;
+    }
+
+    Some(
+        markers
+            .iter()
+            .map(|marker| escape_regex(marker))
+            .collect::<Vec<_>>()
+            .join("|"),
+    )
+}
+
+/// Removes ANSI escape sequences (colors, bold) from a log line
+fn strip_ansi(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // CSI sequences (`ESC [ ... letter`) end at the first ASCII letter
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+        stripped.push(c);
+    }
+
+    stripped
+}
+
+/// Checks whether a log line is the framework's "server ready" banner
+pub fn is_ready_line(framework: &str, line: &str) -> bool {
+    let markers = get_ready_markers(framework);
+    if markers.is_empty() {
+        return false;
+    }
+
+    let line = strip_ansi(line);
+    markers.iter().any(|marker| line.contains(marker))
+}
+
+/// Hosts a server's banner uses for its local URL
+const LOCAL_HOSTS: &[&str] = &["localhost", "127.0.0.1", "0.0.0.0", "[::1]"];
+
+/// Reads the port from the local URL a server prints once it's listening
+/// (e.g. `➜  Local:   http://localhost:4173/`)
+pub fn extract_banner_port(line: &str) -> Option<u16> {
+    let line = strip_ansi(line);
+
+    line.match_indices("://").find_map(|(index, _)| {
+        let address = &line[index + "://".len()..];
+        let host = LOCAL_HOSTS
+            .iter()
+            .find(|host| address.starts_with(**host))?;
+        let port: String = address[host.len()..]
+            .strip_prefix(':')?
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        port.parse::<u16>().ok().filter(|&port| port > 0)
+    })
+}
+
 fn detect_port_from_config(path: &PathBuf, framework: &str) -> Option<u16> {
     match framework {
         "astro" => detect_astro_port(path),
         "nextjs" => detect_nextjs_port(path),
-        "vite" => detect_vite_port(path),
+        // Remix 2 and Qwik run their dev servers through Vite
+        "vite" | "remix" | "qwik" => detect_vite_port(path),
         _ => None,
     }
 }
@@ -81,6 +220,134 @@ fn detect_port_from_package_json(path: &PathBuf) -> Option<u16> {
     None
 }
 
+/// Reads the port from the `web:` process of a Procfile
+fn detect_port_from_procfile(path: &Path) -> Option<u16> {
+    let content = fs::read_to_string(path.join("Procfile")).ok()?;
+    content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("web:"))
+        .and_then(extract_port_from_string)
+}
+
+/// Reads `PORT=` from the env files the dev server loads (.env.local, .env.development, .env)
+fn detect_port_from_env(path: &Path) -> Option<u16> {
+    crate::project_info::read_env_value(path, crate::project_info::DEV_ENV_FILES, "PORT")?
+        .parse::<u16>()
+        .ok()
+        .filter(|&port| port > 0)
+}
+
+/// Reads the host-side port the first compose service publishes
+fn detect_port_from_compose(path: &Path) -> Option<u16> {
+    crate::project_info::COMPOSE_FILES
+        .iter()
+        .find_map(|file| fs::read_to_string(path.join(file)).ok())
+        .and_then(|content| parse_compose_port(&content))
+}
+
+/// Parses the first host port from the first service's `ports:` entries
+///
+/// Handles the short form (`- "8080:80"`, `["8080:80"]`) and the long form
+/// (`- target: 80` / `published: 8080`).
+fn parse_compose_port(content: &str) -> Option<u16> {
+    let mut in_services = false;
+    let mut service_indent = None;
+    let mut in_first_service = false;
+    let mut ports_indent = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let indent = line.len() - trimmed.len();
+        if indent == 0 {
+            if in_first_service {
+                break;
+            }
+            in_services = line.trim_end() == "services:";
+            continue;
+        }
+        if !in_services {
+            continue;
+        }
+
+        let service_indent = *service_indent.get_or_insert(indent);
+        if indent <= service_indent {
+            if in_first_service {
+                // Reached the second service
+                break;
+            }
+            in_first_service = true;
+            continue;
+        }
+
+        if let Some(ports) = ports_indent {
+            // List items may sit at the key's own indentation
+            let in_ports = indent > ports || (indent == ports && trimmed.starts_with('-'));
+            if in_ports {
+                let entry = trimmed.trim_start_matches('-').trim_start();
+                if let Some(port) = compose_entry_port(entry) {
+                    return Some(port);
+                }
+                continue;
+            }
+            ports_indent = None;
+        }
+
+        if let Some(value) = trimmed.strip_prefix("ports:") {
+            let value = value.trim();
+            if value.is_empty() {
+                ports_indent = Some(indent);
+            } else if let Some(port) = value
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .split(',')
+                .find_map(compose_host_port)
+            {
+                return Some(port);
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads the host port from one line of a `ports:` list
+fn compose_entry_port(entry: &str) -> Option<u16> {
+    match entry.split_once(':') {
+        // Long form: only `published` is the host side
+        Some((key, value)) if key.chars().all(|c| c.is_ascii_alphabetic() || c == '_') => {
+            if key != "published" {
+                return None;
+            }
+            let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+            range_start(value)
+        }
+        _ => compose_host_port(entry),
+    }
+}
+
+/// Extracts the host side of a short-form mapping like `"127.0.0.1:8080:80/tcp"`
+///
+/// A bare container port gets a random host port, so it yields None.
+fn compose_host_port(mapping: &str) -> Option<u16> {
+    let mapping = mapping.trim().trim_matches(|c| c == '"' || c == '\'');
+    let mapping = mapping.split('/').next()?;
+
+    let mut parts = mapping.rsplit(':');
+    parts.next()?; // container port
+    let host = parts.next()?;
+    range_start(host)
+}
+
+/// Parses a compose port or the first port of a range like "3000-3001"
+fn range_start(ports: &str) -> Option<u16> {
+    let first = ports.split('-').next()?;
+    first.parse::<u16>().ok().filter(|&port| port > 0)
+}
+
 fn detect_astro_port(path: &PathBuf) -> Option<u16> {
     // Search in astro.config.mjs, astro.config.js, or astro.config.ts
     let config_files = vec![
@@ -126,223 +393,895 @@ fn detect_nextjs_port(path: &PathBuf) -> Option<u16> {
     None
 }
 
+/// Reads the project's Vite config without the `hmr` and `preview` blocks
+///
+/// Both can declare their own `port` (the HMR websocket and `vite preview`),
+/// which must not be mistaken for the dev server's.
+fn read_vite_dev_config(path: &Path) -> Option<String> {
+    let mut content = ["vite.config.js", "vite.config.ts", "vite.config.mjs"]
+        .iter()
+        .find_map(|file| fs::read_to_string(path.join(file)).ok())?;
+
+    if let Some(block) = find_hmr_block(&content) {
+        content = content.replacen(block, "", 1);
+    }
+    let preview_blocks: Vec<String> = find_config_blocks(&content, &["preview"])
+        .into_iter()
+        .map(String::from)
+        .collect();
+    for block in preview_blocks {
+        content = content.replacen(&block, "", 1);
+    }
+
+    Some(content)
+}
+
 fn detect_vite_port(path: &PathBuf) -> Option<u16> {
-    // Vite can have port in vite.config.js, vite.config.ts, or vite.config.mjs
-    let config_files = vec![
-        path.join("vite.config.js"),
-        path.join("vite.config.ts"),
-        path.join("vite.config.mjs"),
-    ];
+    // Search for server: { port: 5173 } or port: 5173
+    extract_port_from_config_file(&read_vite_dev_config(path)?, "port")
+}
 
-    for config_path in config_files {
-        if config_path.exists() {
-            if let Ok(content) = fs::read_to_string(&config_path) {
-                // Search for server: { port: 5173 } or port: 5173
-                if let Some(port) = extract_port_from_config_file(&content, "port") {
-                    return Some(port);
-                }
-            }
+/// Checks whether the Vite dev server sets `strictPort: true`
+fn is_vite_strict_port(path: &Path) -> bool {
+    let Some(content) = read_vite_dev_config(path) else {
+        return false;
+    };
+    let content = strip_js_comments(&content);
+
+    content.match_indices("strictPort").any(|(index, _)| {
+        content[index + "strictPort".len()..]
+            .trim_start_matches(['"', '\''])
+            .trim_start()
+            .strip_prefix(':')
+            .is_some_and(|value| value.trim_start().starts_with("true"))
+    })
+}
+
+/// Returns Vite's `server.hmr` options when they're given as an object
+fn find_hmr_block(content: &str) -> Option<&str> {
+    content.match_indices("hmr").find_map(|(index, _)| {
+        let value = content[index + "hmr".len()..]
+            .trim_start()
+            .strip_prefix(':')?
+            .trim_start();
+        // `hmr: false` has no options (and mustn't pick up the next block)
+        if !value.starts_with('{') {
+            return None;
         }
+        find_config_blocks(&content[index..], &["hmr"])
+            .into_iter()
+            .next()
+    })
+}
+
+/// Detects the HMR websocket port configured in Vite's `server.hmr.port`
+///
+/// Returns None when it isn't set, in which case HMR shares the HTTP port.
+pub fn detect_hmr_port(path: &PathBuf) -> Option<u16> {
+    let framework = crate::detection::detect_framework_from_path(path);
+    if !matches!(framework, "vite" | "sveltekit" | "qwik") {
+        return None;
     }
 
-    None
+    framework_config_files(framework).iter().find_map(|file| {
+        let content = fs::read_to_string(path.join(file)).ok()?;
+        extract_port_from_config_file(find_hmr_block(&content)?, "port")
+    })
 }
 
-fn extract_port_from_config_file(content: &str, key: &str) -> Option<u16> {
-    // Search for patterns like "port: 4321" or "port:4321" or nested "server: { port: 4321 }"
-    // Handle both single-line and multi-line formats
-    // Filter out commented lines to avoid false positives
-    let lines: Vec<&str> = content.lines().collect();
-    
-    for (i, line) in lines.iter().enumerate() {
-        // Skip lines that are comments (starting with // or # or /* after trimming)
-        let trimmed = line.trim();
-        if trimmed.starts_with("//") || trimmed.starts_with("#") || trimmed.starts_with("/*") || trimmed.starts_with("*") {
+/// Removes `//` and `/* */` comments (and `#` comment lines) from a JS/TS config
+///
+/// String literals are kept intact so URLs like `"http://localhost"` survive.
+pub fn strip_js_comments(content: &str) -> String {
+    let mut stripped = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut quote: Option<char> = None;
+    let mut at_line_start = true;
+
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            stripped.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    stripped.push(escaped);
+                }
+            } else if c == q {
+                quote = None;
+            }
             continue;
         }
-        
-        // Direct match: port: 4321 or "port": 4321
-        if line.contains(key) && line.contains(':') {
-            // Try to extract port from this line
-            if let Some(port) = extract_port_from_line(line, key) {
-                return Some(port);
+
+        match c {
+            '"' | '\'' | '`' => {
+                quote = Some(c);
+                stripped.push(c);
             }
-        }
-        
-        // Handle nested structures like: server: { ... port: 4321 ... }
-        // Can be multi-line: "server: {\n  port: 4321\n}"
-        // Use more specific pattern matching: "server:" or "server {" to avoid false positives
-        if line.contains("server:") || line.contains("server {") {
-            // Check the next few lines for a port declaration (multi-line format)
-            let search_range = std::cmp::min(i + 4, lines.len());
-            for j in (i + 1)..search_range {
-                let next_line = match lines.get(j) {
-                    Some(line) => line,
-                    None => continue,
-                };
-                
-                // Skip commented lines
-                let next_trimmed = next_line.trim();
-                if next_trimmed.starts_with("//") 
-                    || next_trimmed.starts_with("#") 
-                    || next_trimmed.starts_with("/*") 
-                    || next_trimmed.starts_with("*") {
-                    continue;
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.peek().is_some_and(|&next| next != '\n') {
+                    chars.next();
                 }
-                
-                if next_line.contains(key) && next_line.contains(':') {
-                    if let Some(port) = extract_port_from_line(next_line, key) {
-                        return Some(port);
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
                     }
+                    // Keep line breaks so line-based callers still see the same lines
+                    if next == '\n' {
+                        stripped.push('\n');
+                    }
+                    previous = next;
+                }
+            }
+            '#' if at_line_start => {
+                while chars.peek().is_some_and(|&next| next != '\n') {
+                    chars.next();
                 }
             }
+            _ => stripped.push(c),
+        }
+
+        if c == '\n' {
+            at_line_start = true;
+        } else if !c.is_whitespace() {
+            at_line_start = false;
         }
     }
-    None
+
+    stripped
 }
 
-fn extract_port_from_line(line: &str, key: &str) -> Option<u16> {
-    // Find the position of "port:" in the line (must be followed by colon)
-    let search_pattern = format!("{}:", key);
-    let key_pos = line.find(&search_pattern)?;
-    let value_start = key_pos + search_pattern.len();
-    
-    if value_start >= line.len() {
-        return None;
+/// Finds the first integer literal in a config value, preferring the least nested one
+///
+/// `Number(process.env.PORT) || 5180` and `parseInt(process.env.PORT, 10) || 5180`
+/// both yield 5180, since the literal outside the call wins. Literals after the
+/// first argument of a call (like parseInt's radix) are never a port.
+fn first_integer_literal(value: &str) -> Option<u16> {
+    let bytes = value.as_bytes();
+    let is_ident_byte = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'.';
+    let mut best: Option<(usize, u16)> = None;
+    // Open brackets, and whether a call's arguments have passed a `,`
+    let mut brackets: Vec<(u8, bool)> = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b @ (b'(' | b'[' | b'{') => brackets.push((b, false)),
+            b')' | b']' | b'}' => {
+                brackets.pop();
+            }
+            b',' => {
+                if let Some((b'(', later_arg)) = brackets.last_mut() {
+                    *later_arg = true;
+                }
+            }
+            b'0'..=b'9' => {
+                let depth = brackets.len();
+                let in_later_arg = brackets.iter().any(|&(_, later_arg)| later_arg);
+                let start = i;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                // Skip digits inside identifiers or versions (`PORT2`, `v1.2`)
+                let standalone = (start == 0 || !is_ident_byte(bytes[start - 1]))
+                    && (i == bytes.len() || !is_ident_byte(bytes[i]));
+                let port = value[start..i].parse::<u16>().ok().filter(|&port| port > 0);
+                if let Some(port) = port.filter(|_| standalone && !in_later_arg) {
+                    let is_shallower = match best {
+                        Some((best_depth, _)) => depth < best_depth,
+                        None => true,
+                    };
+                    if is_shallower {
+                        best = Some((depth, port));
+                    }
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
     }
-    
-    // Extract everything after "port:"
-    let value_part = &line[value_start..];
-    // Split by whitespace, comma, or closing brace to get the number
-    // Filter out empty strings and find the first non-empty part
-    let port_str = value_part
-        .split(|c: char| c.is_whitespace() || c == ',' || c == '}' || c == ')' || c == ']')
-        .find(|s| !s.is_empty())
-        .unwrap_or("")
-        .trim()
-        .trim_matches('"')
-        .trim_matches('\'')
-        .trim_matches('{')
-        .trim_matches('[')
-        .trim_matches('(');
-    
-    port_str
-        .parse::<u16>()
-        .ok()
-        .filter(|&port| port > 0)
-}
 
-pub fn detect_port_deno(_path: &PathBuf) -> Option<u16> {
-    // Deno typically uses port 8000 by default
-    Some(8000)
+    best.map(|(_, port)| port)
 }
 
-fn extract_port_from_string(s: &str) -> Option<u16> {
-    // Search for patterns like --port 3000, -p 3000, PORT=3000, :3000, etc.
-    let words: Vec<&str> = s.split_whitespace().collect();
-
-    for (i, word) in words.iter().enumerate() {
-        // Search for --port or -p followed by a number
-        if (*word == "--port" || *word == "-p") && i + 1 < words.len() {
-            if let Ok(port) = words[i + 1].parse::<u16>() {
-                return Some(port);
+/// Finds where a JS value ends: the next `,` or `;`, or a closing bracket outside nested parens
+fn value_end(value: &str) -> usize {
+    let mut depth = 0i32;
+    value
+        .char_indices()
+        .find(|&(_, c)| {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ => {}
             }
+            depth < 0 || (depth == 0 && matches!(c, ',' | ';'))
+        })
+        .map_or(value.len(), |(end, _)| end)
+}
+
+/// Reads the value of `key: <value>` wherever it appears, regardless of line breaks
+fn find_port_value(content: &str, key: &str) -> Option<u16> {
+    content.match_indices(key).find_map(|(index, _)| {
+        // Only match whole identifiers (not e.g. `clientPort` or `portal`)
+        let preceded_by_ident = content[..index]
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        let rest = content[index + key.len()..].trim_start_matches(['"', '\'']);
+        let followed_by_ident = rest
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        if preceded_by_ident || followed_by_ident {
+            return None;
         }
 
-        // Search for PORT=3000, --port=3000, or -p=3000
-        let port = word
-            .strip_prefix("PORT=")
-            .or_else(|| word.strip_prefix("--port="))
-            .or_else(|| word.strip_prefix("-p="))
-            .and_then(|port_str| port_str.parse::<u16>().ok());
-        
-        if let Some(port) = port {
-            return Some(port);
+        let value = rest.trim_start().strip_prefix(':')?;
+        first_integer_literal(&value[..value_end(value)])
+    })
+}
+
+/// Reads the port assigned to a variable (e.g. `const PORT = process.env.PORT || 4000`)
+fn find_assigned_port(source: &str, name: &str) -> Option<u16> {
+    source.match_indices(name).find_map(|(index, _)| {
+        let preceded_by_ident = source[..index]
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.');
+        if preceded_by_ident {
+            return None;
         }
 
-        // Search for :3000 or localhost:3000 (only if it looks like a valid port)
-        if word.contains(':') {
-            let port = word
-                .split(':')
-                .last()
-                .filter(|port_str| port_str.len() <= 5 && port_str.chars().all(|c| c.is_ascii_digit()))
-                .and_then(|port_str| port_str.parse::<u16>().ok())
-                .filter(|&port| port > 0);
-            
-            if let Some(port) = port {
-                return Some(port);
-            }
+        // Skip comparisons (`PORT == 80`) and arrow functions (`port => ...`)
+        let value = source[index + name.len()..]
+            .trim_start()
+            .strip_prefix('=')
+            .filter(|value| !value.starts_with(['=', '>']))?;
+        let end = value.find([';', '\n']).unwrap_or(value.len());
+        first_integer_literal(&value[..end])
+    })
+}
+
+/// Extracts the port a Node server passes to `listen(...)`
+///
+/// Handles `app.listen(4000)`, `server.listen({ port: 4000 })` and a variable
+/// argument (`app.listen(PORT)`) whose assignment has a literal fallback.
+fn extract_listen_port(source: &str) -> Option<u16> {
+    let source = strip_js_comments(source);
+
+    source.match_indices(".listen(").find_map(|(index, call)| {
+        let args = source[index + call.len()..].trim_start();
+        let first_arg = args[..value_end(args)].trim();
+
+        if first_arg.starts_with('{') {
+            return find_port_value(first_arg, "port");
         }
-    }
+        let is_identifier = first_arg.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && first_arg
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+        if is_identifier {
+            return find_assigned_port(&source, first_arg);
+        }
+        first_integer_literal(first_arg)
+    })
+}
 
-    None
+/// Best-effort: reads the port a plain Node server listens on from its entry file
+fn detect_port_from_source(path: &Path) -> Option<u16> {
+    let entry_point = crate::project_info::detect_entry_point(path)?;
+    extract_listen_port(&fs::read_to_string(entry_point).ok()?)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use tempfile::TempDir;
+/// Extracts a port from a JS/TS config (e.g. `server: { host: true, port: 5180 }`)
+///
+/// Comments are ignored and line breaks don't matter. A `key` inside a
+/// `server` block wins over one elsewhere in the file.
+fn extract_port_from_config_file(content: &str, key: &str) -> Option<u16> {
+    let content = strip_js_comments(content);
 
-    fn create_temp_dir() -> TempDir {
-        tempfile::tempdir().expect("Failed to create temp directory")
-    }
+    find_config_blocks(&content, &["server"])
+        .into_iter()
+        .find_map(|block| find_port_value(block, key))
+        .or_else(|| find_port_value(&content, key))
+}
 
-    fn create_temp_file(dir: &PathBuf, name: &str, content: &str) -> PathBuf {
-        let file_path = dir.join(name);
-        fs::write(&file_path, content).expect("Failed to write temp file");
-        file_path
+/// Config files that can configure the dev server, per framework
+fn framework_config_files(framework: &str) -> &'static [&'static str] {
+    match framework {
+        "astro" => &["astro.config.mjs", "astro.config.js", "astro.config.ts"],
+        "nextjs" => &["next.config.js", "next.config.mjs", "next.config.ts"],
+        // SvelteKit, Remix and Qwik run on Vite, so they're configured in vite.config.*
+        "vite" | "sveltekit" | "remix" | "qwik" => {
+            &["vite.config.js", "vite.config.ts", "vite.config.mjs"]
+        }
+        "nuxt" => &["nuxt.config.js", "nuxt.config.ts"],
+        _ => &[],
     }
+}
 
-    #[test]
-    fn test_get_default_port_astro() {
-        assert_eq!(get_default_port("astro"), Some(4321));
+/// Detects whether the project's dev server is configured to serve HTTPS
+///
+/// Checks the dev script for `--https`/`--experimental-https` flags and the
+/// framework config for an `https` option (Vite `server.https`, Nuxt
+/// `devServer.https`) or an SSL plugin. `framework` is the project's detected
+/// framework. Returns None for frameworks without a known config file.
+pub fn detect_https(path: &PathBuf, framework: &str) -> Option<bool> {
+    let config_files = framework_config_files(framework);
+    if config_files.is_empty() {
+        return None;
     }
 
-    #[test]
-    fn test_get_default_port_nextjs() {
-        assert_eq!(get_default_port("nextjs"), Some(3000));
+    let dev_script_enables_https = crate::project_info::get_package_json_scripts(path)
+        .and_then(|scripts| scripts.get("dev").cloned())
+        .is_some_and(|dev| {
+            dev.split_whitespace()
+                .any(|word| word == "--https" || word == "--experimental-https")
+        });
+    if dev_script_enables_https {
+        return Some(true);
     }
 
-    #[test]
-    fn test_get_default_port_vite() {
-        assert_eq!(get_default_port("vite"), Some(5173));
-    }
+    let from_config = config_files.iter().find_map(|file| {
+        fs::read_to_string(path.join(file))
+            .ok()
+            .and_then(|content| extract_https_from_config(&content))
+    });
 
-    #[test]
-    fn test_get_default_port_react() {
-        assert_eq!(get_default_port("react"), Some(3000));
-    }
+    Some(from_config.unwrap_or(false))
+}
 
-    #[test]
-    fn test_get_default_port_sveltekit() {
-        assert_eq!(get_default_port("sveltekit"), Some(5173));
-    }
+fn extract_https_from_config(content: &str) -> Option<bool> {
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("//") || trimmed.starts_with('*') || trimmed.starts_with("/*") {
+            continue;
+        }
 
-    #[test]
-    fn test_get_default_port_nuxt() {
-        assert_eq!(get_default_port("nuxt"), Some(3000));
-    }
+        // Plugins that set up a self-signed certificate imply HTTPS
+        if trimmed.contains("basicSsl(") || trimmed.contains("mkcert(") {
+            return Some(true);
+        }
 
-    #[test]
-    fn test_get_default_port_unknown() {
-        assert_eq!(get_default_port("unknown"), Some(3000));
-    }
+        for (index, _) in line.match_indices("https") {
+            // Skip identifiers that merely end in "https" (e.g. `useHttps`)
+            let preceded_by_ident = line[..index]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            if preceded_by_ident {
+                continue;
+            }
 
-    #[test]
-    fn test_get_default_port_node() {
-        assert_eq!(get_default_port("node"), Some(3000));
-    }
+            let rest = line[index + "https".len()..].trim_start_matches(['"', '\'']);
+            let value = match rest.strip_prefix(':') {
+                // `https://...` is a URL, not the option
+                Some(value) if !value.starts_with('/') => value.trim_start(),
+                _ => continue,
+            };
 
-    #[test]
-    fn test_detect_port_deno() {
-        let temp_dir = create_temp_dir();
-        let dir_path = temp_dir.path().to_path_buf();
-        assert_eq!(detect_port_deno(&dir_path), Some(8000));
+            return Some(!value.starts_with("false"));
+        }
     }
 
-    #[test]
-    fn test_extract_port_from_string_port_flag() {
+    None
+}
+
+/// Hosts that just mean "this machine" rather than a configured alias
+const LOOPBACK_HOSTS: &[&str] = &["localhost", "127.0.0.1", "0.0.0.0", "::", "::1"];
+
+/// Detects the custom hostname the dev server is configured for (e.g. `myapp.local`)
+///
+/// Reads `server.host` (Nuxt `devServer.host`) when it's a string, then the
+/// first name in `server.allowedHosts`. Loopback addresses and wildcards
+/// aren't aliases, so they're skipped. `framework` is the project's detected
+/// framework.
+pub fn detect_preferred_host(path: &Path, framework: &str) -> Option<String> {
+    framework_config_files(framework).iter().find_map(|file| {
+        let content = strip_js_comments(&fs::read_to_string(path.join(file)).ok()?);
+        find_config_blocks(&content, &["server", "devServer"])
+            .into_iter()
+            .find_map(extract_preferred_host)
+    })
+}
+
+fn extract_preferred_host(server_block: &str) -> Option<String> {
+    // `hmr.host` is the websocket's host, not the page's
+    let block = match find_hmr_block(server_block) {
+        Some(hmr) => server_block.replacen(hmr, "", 1),
+        None => server_block.to_string(),
+    };
+
+    let host = find_key_values(&block, "host").filter_map(|value| {
+        crate::project_info::string_literals(&value[..value_end(value)])
+            .into_iter()
+            .next()
+    });
+    // `.myapp.local` allows the domain and its subdomains
+    let allowed_hosts = find_key_values(&block, "allowedHosts")
+        .filter(|value| value.starts_with('['))
+        .flat_map(|value| {
+            crate::project_info::string_literals(&value[..value.find(']').unwrap_or(value.len())])
+        })
+        .map(|host| host.trim_start_matches('.').to_string());
+
+    let mut candidates = host.chain(allowed_hosts);
+    candidates.find(|host| {
+        !host.is_empty() && !host.contains(['*', ' ']) && !LOOPBACK_HOSTS.contains(&host.as_str())
+    })
+}
+
+/// Returns the value following each `key:` in a config, matching whole identifiers
+fn find_key_values<'a>(content: &'a str, key: &'a str) -> impl Iterator<Item = &'a str> {
+    content.match_indices(key).filter_map(move |(index, _)| {
+        let preceded_by_ident = content[..index]
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        if preceded_by_ident {
+            return None;
+        }
+
+        let value = content[index + key.len()..]
+            .trim_start_matches(['"', '\''])
+            .trim_start()
+            .strip_prefix(':')?;
+        Some(value.trim_start())
+    })
+}
+
+/// Config keys whose values hold dev server proxy/rewrite rules
+const PROXY_CONFIG_KEYS: &[&str] = &["proxy", "devProxy", "rewrites"];
+
+/// Detects the backend URLs the dev server proxies requests to
+///
+/// Best-effort: reads the `proxy` field of package.json (Create React App) and
+/// the proxy/rewrite blocks of the framework config (Vite `server.proxy`, Nuxt
+/// `devProxy`, Next.js `rewrites`), collecting absolute URLs written as string
+/// literals. Targets computed at runtime aren't detected.
+pub fn detect_proxy_targets(path: &PathBuf) -> Vec<String> {
+    let mut targets = Vec::new();
+
+    if let Some(proxy) = crate::project_info::read_package_json(path)
+        .and_then(|package_json| package_json.get("proxy")?.as_str().map(String::from))
+    {
+        targets.push(proxy);
+    }
+
+    let framework = crate::detection::detect_framework_from_path(path);
+    for file in framework_config_files(framework) {
+        if let Ok(content) = fs::read_to_string(path.join(file)) {
+            for block in find_config_blocks(&content, PROXY_CONFIG_KEYS) {
+                targets.extend(extract_urls(block));
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    targets.retain(|target| seen.insert(target.clone()));
+    targets
+}
+
+/// Returns the bracketed value following each of the given config keys
+pub fn find_config_blocks<'a>(content: &'a str, keys: &[&str]) -> Vec<&'a str> {
+    let mut blocks = Vec::new();
+
+    for key in keys {
+        for (index, _) in content.match_indices(key) {
+            // Only match whole identifiers (not e.g. `proxyTimeout` or `useProxy`)
+            let preceded_by_ident = content[..index]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            let followed_by_ident = content[index + key.len()..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            if preceded_by_ident || followed_by_ident {
+                continue;
+            }
+
+            let rest = &content[index + key.len()..];
+            let Some(start) = rest.find(['{', '[']) else {
+                continue;
+            };
+
+            let mut depth = 0;
+            for (offset, c) in rest[start..].char_indices() {
+                match c {
+                    '{' | '[' => depth += 1,
+                    '}' | ']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            blocks.push(&rest[start..start + offset + 1]);
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Extracts absolute http(s)/ws(s) URLs written as string literals
+fn extract_urls(block: &str) -> Vec<String> {
+    let mut urls = Vec::new();
+    let mut chars = block.char_indices();
+
+    while let Some((start, quote)) = chars.next() {
+        if !matches!(quote, '"' | '\'' | '`') {
+            continue;
+        }
+
+        let Some((end, _)) = chars.by_ref().find(|(_, c)| *c == quote) else {
+            break;
+        };
+
+        let literal = &block[start + 1..end];
+        let is_url = ["http://", "https://", "ws://", "wss://"]
+            .iter()
+            .any(|scheme| literal.starts_with(scheme));
+        if is_url {
+            urls.push(literal.to_string());
+        }
+    }
+
+    urls
+}
+
+/// RedwoodJS sides and their default dev ports, primary first
+const REDWOOD_SIDES: &[(&str, u16)] = &[("web", 8910), ("api", 8911)];
+
+/// Reads the `port` of each side from a redwood.toml
+///
+/// Values may use env interpolation with a fallback (`"${WEB_DEV_PORT:8910}"`),
+/// in which case the fallback is used.
+fn parse_redwood_ports(content: &str) -> Vec<(String, u16)> {
+    let mut section = "";
+    let mut configured: Vec<(&str, u16)> = Vec::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if let Some(name) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            section = name.trim();
+            continue;
+        }
+
+        let Some((key, value)) = trimmed.split_once('=') else {
+            continue;
+        };
+        if key.trim() != "port" {
+            continue;
+        }
+
+        let value = value
+            .split('#')
+            .next()
+            .unwrap_or(value)
+            .trim()
+            .trim_matches('"');
+        let value = match value.strip_prefix("${") {
+            Some(interpolated) => interpolated
+                .trim_end_matches('}')
+                .split_once(':')
+                .map_or("", |(_, fallback)| fallback),
+            None => value,
+        };
+        if let Ok(port) = value.parse::<u16>() {
+            configured.push((section, port));
+        }
+    }
+
+    REDWOOD_SIDES
+        .iter()
+        .map(|(side, default_port)| {
+            let port = configured
+                .iter()
+                .find(|(section, _)| section == side)
+                .map_or(*default_port, |(_, port)| *port);
+            (side.to_string(), port)
+        })
+        .collect()
+}
+
+/// Detects the labeled ports of projects that run several dev servers
+///
+/// Currently recognizes RedwoodJS (`redwood.toml` `[web]`/`[api]` ports). The
+/// primary server comes first; returns an empty list for single-server projects.
+pub fn detect_server_ports(path: &Path) -> Vec<(String, u16)> {
+    fs::read_to_string(path.join("redwood.toml"))
+        .map(|content| parse_redwood_ports(&content))
+        .unwrap_or_default()
+}
+
+pub fn detect_port_deno(_path: &PathBuf) -> Option<u16> {
+    // Deno typically uses port 8000 by default
+    Some(8000)
+}
+
+/// Parses a port written literally or as a shell default like `${APP_PORT:-3001}`
+fn parse_port_value(value: &str) -> Option<u16> {
+    let value = value.trim_matches(|c| c == '"' || c == '\'');
+    let value = value
+        .strip_prefix("${")
+        .and_then(|expansion| expansion.strip_suffix('}'))
+        .and_then(|expansion| expansion.split_once('-'))
+        .map_or(value, |(_, default)| default);
+    value.parse::<u16>().ok()
+}
+
+fn extract_port_from_string(s: &str) -> Option<u16> {
+    // Search for patterns like --port 3000, -p 3000, PORT=3000, :3000, etc.
+    let words: Vec<&str> = s.split_whitespace().collect();
+
+    for (i, word) in words.iter().enumerate() {
+        // Search for --port or -p followed by a number
+        if (*word == "--port" || *word == "-p") && i + 1 < words.len() {
+            if let Some(port) = parse_port_value(words[i + 1]) {
+                return Some(port);
+            }
+        }
+
+        // Search for PORT=3000, --port=3000, or -p=3000
+        let port = word
+            .strip_prefix("PORT=")
+            .or_else(|| word.strip_prefix("--port="))
+            .or_else(|| word.strip_prefix("-p="))
+            .and_then(parse_port_value);
+        
+        if let Some(port) = port {
+            return Some(port);
+        }
+
+        // Search for :3000 or localhost:3000 (only if it looks like a valid port)
+        if word.contains(':') {
+            let port = word
+                .split(':')
+                .last()
+                .filter(|port_str| port_str.len() <= 5 && port_str.chars().all(|c| c.is_ascii_digit()))
+                .and_then(|port_str| port_str.parse::<u16>().ok())
+                .filter(|&port| port > 0);
+            
+            if let Some(port) = port {
+                return Some(port);
+            }
+        }
+    }
+
+    None
+}
+
+/// Ports commonly held by OS services or popular local tools, with the likely occupant
+const WELL_KNOWN_PORTS: &[(u16, &str)] = &[
+    (3000, "a common default for dev servers (Rails, Grafana, Create React App), so another local app may already use it"),
+    (3306, "MySQL's default port"),
+    (5000, "used by the AirPlay Receiver on macOS 12+ (System Settings > General > AirDrop & Handoff)"),
+    (5432, "PostgreSQL's default port"),
+    (6379, "Redis's default port"),
+    (7000, "used by the AirPlay Receiver (Control Center) on macOS 12+"),
+    (8080, "a common default for proxies, Tomcat and other local tools, so another app may already use it"),
+    (27017, "MongoDB's default port"),
+];
+
+/// First port of the ephemeral range the OS hands out for outgoing connections
+const EPHEMERAL_PORT_START: u16 = 49152;
+
+/// Returns advisory warnings for a port that is likely to collide with something
+///
+/// This doesn't probe the port; it explains up front why a default port may
+/// turn out to be in use (OS services, databases, privileged or ephemeral ranges).
+pub fn port_warnings(port: u16) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if port == 0 {
+        warnings.push("Port 0 isn't a real port; the OS assigns a random one".to_string());
+        return warnings;
+    }
+
+    if port < 1024 {
+        warnings.push(format!(
+            "Port {} is a privileged port and usually requires root to bind",
+            port
+        ));
+    }
+
+    if port >= EPHEMERAL_PORT_START {
+        warnings.push(format!(
+            "Port {} is in the ephemeral range the OS assigns to outgoing connections, so it may be taken at random",
+            port
+        ));
+    }
+
+    if let Some((_, occupant)) = WELL_KNOWN_PORTS.iter().find(|(known, _)| *known == port) {
+        warnings.push(format!("Port {} is {}", port, occupant));
+    }
+
+    warnings
+}
+
+/// Checks whether a port can currently be bound on the loopback interface
+pub fn is_port_available(port: u16) -> bool {
+    TcpListener::bind(("127.0.0.1", port)).is_ok()
+}
+
+/// Finds the first available port starting at `start` (inclusive)
+///
+/// Returns None if every port up to 65535 is taken.
+pub fn find_available_port(start: u16) -> Option<u16> {
+    (start.max(1)..=u16::MAX).find(|&port| is_port_available(port))
+}
+
+/// Detects the port for a project directory, dispatching on its runtime
+fn detect_project_port(path: &PathBuf) -> Option<u16> {
+    let is_deno = (path.join("deno.json").exists() || path.join("deno.jsonc").exists())
+        && !path.join("package.json").exists();
+
+    if is_deno {
+        detect_port_deno(path)
+    } else {
+        detect_port(path)
+    }
+}
+
+/// Detects each project's port and suggests a remap for projects that share one
+///
+/// Returns `(path, port)` for every project involved in a conflict. The first
+/// project claiming a port keeps it; later claimants get the next free port.
+pub fn resolve_port_conflicts(paths: &[PathBuf]) -> Vec<(String, u16)> {
+    let detected: Vec<(String, u16)> = paths
+        .iter()
+        .filter_map(|path| {
+            detect_project_port(path).map(|port| (path.to_string_lossy().to_string(), port))
+        })
+        .collect();
+
+    assign_conflict_ports(&detected, find_available_port)
+}
+
+/// Assigns distinct ports to conflicting projects
+///
+/// Kept separate from port probing so the assignment logic can be tested
+/// without depending on which ports are free on the machine.
+fn assign_conflict_ports<F>(detected: &[(String, u16)], find_free: F) -> Vec<(String, u16)>
+where
+    F: Fn(u16) -> Option<u16>,
+{
+    // Ports claimed by any project are never handed out as a remap target
+    let mut taken: HashSet<u16> = detected.iter().map(|(_, port)| *port).collect();
+    let mut claimed: HashSet<u16> = HashSet::new();
+    let mut assignments = Vec::new();
+
+    for (index, (path, port)) in detected.iter().enumerate() {
+        let is_conflicting = detected
+            .iter()
+            .enumerate()
+            .any(|(other, (_, other_port))| other != index && other_port == port);
+
+        if !is_conflicting {
+            continue;
+        }
+
+        // First claimant keeps its original port
+        if claimed.insert(*port) {
+            assignments.push((path.clone(), *port));
+            continue;
+        }
+
+        // Probe upward from the original port, skipping ports other projects claim
+        let mut remapped = find_free(port.saturating_add(1));
+        while let Some(candidate) = remapped {
+            if !taken.contains(&candidate) {
+                break;
+            }
+            remapped = candidate.checked_add(1).and_then(&find_free);
+        }
+
+        if let Some(remapped) = remapped {
+            taken.insert(remapped);
+            assignments.push((path.clone(), remapped));
+        }
+    }
+
+    assignments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_temp_dir() -> TempDir {
+        tempfile::tempdir().expect("Failed to create temp directory")
+    }
+
+    fn create_temp_file(dir: &PathBuf, name: &str, content: &str) -> PathBuf {
+        let file_path = dir.join(name);
+        fs::write(&file_path, content).expect("Failed to write temp file");
+        file_path
+    }
+
+    #[test]
+    fn test_get_default_port_astro() {
+        assert_eq!(get_default_port("astro"), Some(4321));
+    }
+
+    #[test]
+    fn test_get_default_port_nextjs() {
+        assert_eq!(get_default_port("nextjs"), Some(3000));
+    }
+
+    #[test]
+    fn test_get_default_port_remix() {
+        assert_eq!(get_default_port("remix"), Some(3000));
+    }
+
+    #[test]
+    fn test_get_default_port_solidstart() {
+        assert_eq!(get_default_port("solidstart"), Some(3000));
+    }
+
+    #[test]
+    fn test_get_default_port_angular() {
+        assert_eq!(get_default_port("angular"), Some(4200));
+    }
+
+    #[test]
+    fn test_get_default_port_gatsby() {
+        assert_eq!(get_default_port("gatsby"), Some(8000));
+    }
+
+    #[test]
+    fn test_get_default_port_qwik() {
+        assert_eq!(get_default_port("qwik"), Some(5173));
+    }
+
+    #[test]
+    fn test_get_default_port_vite() {
+        assert_eq!(get_default_port("vite"), Some(5173));
+    }
+
+    #[test]
+    fn test_get_default_port_react() {
+        assert_eq!(get_default_port("react"), Some(3000));
+    }
+
+    #[test]
+    fn test_get_default_port_sveltekit() {
+        assert_eq!(get_default_port("sveltekit"), Some(5173));
+    }
+
+    #[test]
+    fn test_get_default_port_nuxt() {
+        assert_eq!(get_default_port("nuxt"), Some(3000));
+    }
+
+    #[test]
+    fn test_get_default_port_unknown() {
+        assert_eq!(get_default_port("unknown"), Some(3000));
+    }
+
+    #[test]
+    fn test_get_default_port_node() {
+        assert_eq!(get_default_port("node"), Some(3000));
+    }
+
+    #[test]
+    fn test_detect_port_deno() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        assert_eq!(detect_port_deno(&dir_path), Some(8000));
+    }
+
+    #[test]
+    fn test_extract_port_from_string_port_flag() {
         assert_eq!(extract_port_from_string("--port 3000"), Some(3000));
         assert_eq!(extract_port_from_string("-p 5173"), Some(5173));
     }
@@ -353,6 +1292,20 @@ mod tests {
         assert_eq!(extract_port_from_string("PORT=8080 dev"), Some(8080));
     }
 
+    #[test]
+    fn test_extract_port_from_string_parameter_expansion_default() {
+        assert_eq!(
+            extract_port_from_string("PORT=${APP_PORT:-3001} next dev"),
+            Some(3001)
+        );
+        assert_eq!(
+            extract_port_from_string("vite --port ${PORT-5174}"),
+            Some(5174)
+        );
+        // No default to fall back on
+        assert_eq!(extract_port_from_string("PORT=${APP_PORT} next dev"), None);
+    }
+
     #[test]
     fn test_extract_port_from_string_port_equals() {
         assert_eq!(extract_port_from_string("--port=3000"), Some(3000));
@@ -417,30 +1370,181 @@ mod tests {
     }
 
     #[test]
-    fn test_detect_astro_port() {
+    fn test_detect_port_from_env() {
         let temp_dir = create_temp_dir();
         let dir_path = temp_dir.path().to_path_buf();
         create_temp_file(
             &dir_path,
-            "astro.config.mjs",
-            "export default { server: { port: 4321 } }",
+            "package.json",
+            r#"{"scripts": {"dev": "next dev"}}"#,
         );
-
-        assert_eq!(detect_astro_port(&dir_path), Some(4321));
-    }
-
-    #[test]
-    fn test_detect_nextjs_port() {
-        let temp_dir = create_temp_dir();
-        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(&dir_path, "next.config.js", "module.exports = {};\n");
         create_temp_file(
             &dir_path,
-            "next.config.js",
-            "module.exports = { port: 3000 }",
+            ".env",
+            "DATABASE_URL=postgres://localhost/app\nPORT=4000\n",
         );
+        assert_eq!(detect_port(&dir_path), Some(4000));
 
-        assert_eq!(detect_nextjs_port(&dir_path), Some(3000));
-    }
+        // .env.local overrides .env
+        create_temp_file(&dir_path, ".env.local", "export PORT=\"4100\" # local\n");
+        assert_eq!(detect_port(&dir_path), Some(4100));
+
+        // A script flag still wins
+        create_temp_file(
+            &dir_path,
+            "package.json",
+            r#"{"scripts": {"dev": "next dev --port 3001"}}"#,
+        );
+        assert_eq!(detect_port(&dir_path), Some(3001));
+    }
+
+    #[test]
+    fn test_parse_compose_port_short_form() {
+        let content = r#"services:
+  web:
+    build: .
+    ports:
+      - "3000"
+      - "127.0.0.1:8080:80/tcp"
+  db:
+    image: postgres
+    ports:
+      - "5432:5432"
+"#;
+        assert_eq!(parse_compose_port(content), Some(8080));
+        assert_eq!(
+            parse_compose_port("services:\n  app:\n    ports: [\"4000:3000\"]\n"),
+            Some(4000)
+        );
+    }
+
+    #[test]
+    fn test_parse_compose_port_long_form() {
+        let content = r#"services:
+  web:
+    ports:
+    - target: 80
+      published: "8081"
+      protocol: tcp
+    environment:
+      PORT: 80
+"#;
+        assert_eq!(parse_compose_port(content), Some(8081));
+        // Only the first service is considered
+        assert_eq!(
+            parse_compose_port(
+                "services:\n  web:\n    image: nginx\n  db:\n    ports:\n      - 5432:5432\n"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_detect_port_from_procfile() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "package.json",
+            r#"{"scripts": {"start": "node server.js"}}"#,
+        );
+        create_temp_file(
+            &dir_path,
+            "Procfile",
+            "release: npm run migrate\nweb: node server.js --port 4000\n",
+        );
+        assert_eq!(detect_port_from_procfile(&dir_path), Some(4000));
+        assert_eq!(detect_port(&dir_path), Some(4000));
+    }
+
+    #[test]
+    fn test_extract_listen_port() {
+        assert_eq!(
+            extract_listen_port("const app = express();\napp.listen(4000, () => {});\n"),
+            Some(4000)
+        );
+        assert_eq!(
+            extract_listen_port("server.listen({\n  host: '0.0.0.0',\n  port: 4000,\n});\n"),
+            Some(4000)
+        );
+        assert_eq!(
+            extract_listen_port(
+                "const PORT = process.env.PORT || 4001;\napp.listen(PORT, () => console.log(PORT));\n"
+            ),
+            Some(4001)
+        );
+        assert_eq!(
+            extract_listen_port("// app.listen(4000)\napp.listen(port);\n"),
+            None
+        );
+        assert_eq!(
+            extract_listen_port(
+                "const port = parseInt(process.env.PORT, 10);\napp.listen(port);\n"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_detect_port_from_source_entry_point() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "package.json",
+            r#"{"main": "server.js", "scripts": {"start": "node server.js"}}"#,
+        );
+        create_temp_file(
+            &dir_path,
+            "server.js",
+            "const http = require('http');\nhttp.createServer(handler).listen(4000);\n",
+        );
+        assert_eq!(detect_port(&dir_path), Some(4000));
+    }
+
+    #[test]
+    fn test_detect_port_from_compose_before_default() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "package.json",
+            r#"{"scripts": {"start": "node server.js"}}"#,
+        );
+        create_temp_file(
+            &dir_path,
+            "docker-compose.yml",
+            "services:\n  app:\n    build: .\n    ports:\n      - \"8080:3000\"\n",
+        );
+        assert_eq!(detect_port(&dir_path), Some(8080));
+    }
+
+    #[test]
+    fn test_detect_astro_port() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "astro.config.mjs",
+            "export default { server: { port: 4321 } }",
+        );
+
+        assert_eq!(detect_astro_port(&dir_path), Some(4321));
+    }
+
+    #[test]
+    fn test_detect_nextjs_port() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "next.config.js",
+            "module.exports = { port: 3000 }",
+        );
+
+        assert_eq!(detect_nextjs_port(&dir_path), Some(3000));
+    }
 
     #[test]
     fn test_detect_vite_port() {
@@ -455,6 +1559,168 @@ mod tests {
         assert_eq!(detect_vite_port(&dir_path), Some(5173));
     }
 
+    #[test]
+    fn test_extract_port_from_config_file_single_line_server_block() {
+        let content = "export default defineConfig({ plugins: [react()], server: { host: true, port: 5180 } });";
+        assert_eq!(extract_port_from_config_file(content, "port"), Some(5180));
+
+        // Quoted keys and values spread over lines
+        let content = "module.exports = {\n  \"server\": {\n    \"port\":\n      8080\n  }\n}";
+        assert_eq!(extract_port_from_config_file(content, "port"), Some(8080));
+    }
+
+    #[test]
+    fn test_extract_port_from_config_file_ignores_comments() {
+        let content = r#"export default defineConfig({
+  server: {
+    // port: 3000,
+    /* port: 4000, */
+    proxy: { "/api": "http://localhost:8080" },
+    strictPort: true, port: 5180,
+  },
+});"#;
+
+        assert_eq!(extract_port_from_config_file(content, "port"), Some(5180));
+        assert_eq!(
+            extract_port_from_config_file("// server: { port: 3000 }\nexport default {};", "port"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_port_from_config_file_env_fallback() {
+        let content =
+            "export default { server: { port: parseInt(process.env.PORT, 10) || 5180, host: true } }";
+        assert_eq!(extract_port_from_config_file(content, "port"), Some(5180));
+
+        let content = "export default { server: { port: Number(process.env.PORT ?? 4321) } }";
+        assert_eq!(extract_port_from_config_file(content, "port"), Some(4321));
+
+        // The radix isn't a port
+        let content = "export default { server: { port: parseInt(process.env.PORT, 10) } }";
+        assert_eq!(extract_port_from_config_file(content, "port"), None);
+    }
+
+    #[test]
+    fn test_extract_port_from_config_file_prefers_server_block() {
+        let content = "export default { preview: { port: 4173 }, server: { port: 5173 } }";
+        assert_eq!(extract_port_from_config_file(content, "port"), Some(5173));
+    }
+
+    #[test]
+    fn test_detect_vite_port_ignores_preview_port() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        let config = create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default defineConfig({\n  preview: { port: 4173 },\n  server: { port: 5180 },\n});\n",
+        );
+        assert_eq!(detect_vite_port(&dir_path), Some(5180));
+
+        // Only a preview port: the dev server falls back to the default
+        fs::write(
+            &config,
+            "export default defineConfig({ preview: { port: 4173 } });\n",
+        )
+        .unwrap();
+        assert_eq!(detect_vite_port(&dir_path), None);
+        assert_eq!(detect_port(&dir_path), Some(5173));
+    }
+
+    #[test]
+    fn test_detect_port_vite_strict_port_beats_script_flag() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "package.json",
+            r#"{"scripts": {"dev": "vite --port 3000"}}"#,
+        );
+        let config = create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default defineConfig({ server: { port: 5180 } });\n",
+        );
+        // The CLI flag overrides server.port
+        assert_eq!(detect_port(&dir_path), Some(3000));
+
+        fs::write(
+            &config,
+            "export default defineConfig({\n  server: { port: 5180, strictPort: true },\n});\n",
+        )
+        .unwrap();
+        assert_eq!(detect_port(&dir_path), Some(5180));
+    }
+
+    #[test]
+    fn test_detect_hmr_port_distinct_from_http_port() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            r#"export default defineConfig({
+  server: {
+    hmr: { port: 24678 },
+    port: 3000,
+  },
+});"#,
+        );
+
+        assert_eq!(detect_hmr_port(&dir_path), Some(24678));
+        assert_eq!(detect_vite_port(&dir_path), Some(3000));
+    }
+
+    #[test]
+    fn test_detect_hmr_port_not_configured() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            r#"export default defineConfig({
+  server: { hmr: false },
+  preview: { port: 4173 },
+});"#,
+        );
+
+        assert_eq!(detect_hmr_port(&dir_path), None);
+    }
+
+    #[test]
+    fn test_detect_server_ports_redwood() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "redwood.toml",
+            r#"[web]
+  title = "Redwood App"
+  port = 3000 # web dev server
+  apiUrl = "/.redwood/functions"
+[api]
+  port = "${API_DEV_PORT:4000}"
+[browser]
+  open = true
+"#,
+        );
+
+        assert_eq!(
+            detect_server_ports(&dir_path),
+            vec![("web".to_string(), 3000), ("api".to_string(), 4000)]
+        );
+        assert_eq!(detect_port(&dir_path), Some(3000));
+    }
+
+    #[test]
+    fn test_parse_redwood_ports_defaults() {
+        assert_eq!(
+            parse_redwood_ports("[web]\n  title = \"App\"\n"),
+            vec![("web".to_string(), 8910), ("api".to_string(), 8911)]
+        );
+    }
+
     #[test]
     fn test_detect_port_default() {
         let temp_dir = create_temp_dir();
@@ -466,4 +1732,314 @@ mod tests {
         let port = detect_port(&dir_path);
         assert!(port.is_some());
     }
+
+    #[test]
+    fn test_assign_conflict_ports_remaps_later_claimants() {
+        let detected = vec![
+            ("a".to_string(), 3000),
+            ("b".to_string(), 3000),
+            ("c".to_string(), 3000),
+            ("d".to_string(), 3001),
+        ];
+
+        let assignments = assign_conflict_ports(&detected, Some);
+
+        // "d" is not in conflict, so it's not reported; 3001 is skipped as a remap target
+        assert_eq!(
+            assignments,
+            vec![
+                ("a".to_string(), 3000),
+                ("b".to_string(), 3002),
+                ("c".to_string(), 3003),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_assign_conflict_ports_skips_busy_ports() {
+        let detected = vec![("a".to_string(), 5173), ("b".to_string(), 5173)];
+
+        let assignments =
+            assign_conflict_ports(&detected, |start| (start..=u16::MAX).find(|&port| port != 5174));
+        assert_eq!(assignments[1], ("b".to_string(), 5175));
+    }
+
+    #[test]
+    fn test_resolve_port_conflicts_distinct_assignments() {
+        let temp_dir = create_temp_dir();
+        let paths: Vec<PathBuf> = ["one", "two", "three"]
+            .iter()
+            .map(|name| {
+                let project_dir = temp_dir.path().join(name);
+                fs::create_dir(&project_dir).expect("Failed to create project dir");
+                create_temp_file(&project_dir, "package.json", r#"{}"#);
+                project_dir
+            })
+            .collect();
+
+        let assignments = resolve_port_conflicts(&paths);
+        assert_eq!(assignments.len(), 3);
+        assert_eq!(assignments[0].1, 3000);
+
+        let unique: HashSet<u16> = assignments.iter().map(|(_, port)| *port).collect();
+        assert_eq!(unique.len(), 3, "Each project should get a distinct port");
+    }
+
+    #[test]
+    fn test_detect_https_vite_enabled() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default defineConfig({\n  server: {\n    https: true,\n    port: 5173\n  }\n})",
+        );
+
+        assert_eq!(detect_https(&dir_path, "vite"), Some(true));
+    }
+
+    #[test]
+    fn test_detect_https_vite_disabled() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default defineConfig({\n  server: {\n    https: false,\n    proxy: { '/api': 'https://api.example.com' }\n  }\n})",
+        );
+
+        assert_eq!(detect_https(&dir_path, "vite"), Some(false));
+    }
+
+    #[test]
+    fn test_detect_https_vite_without_option() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default { server: { port: 5173 } }",
+        );
+
+        assert_eq!(detect_https(&dir_path, "vite"), Some(false));
+    }
+
+    #[test]
+    fn test_detect_preferred_host_vite_server_host() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default defineConfig({\n  server: {\n    // reachable via /etc/hosts\n    host: 'myapp.local',\n    port: 5173,\n    hmr: { host: 'localhost' }\n  }\n})",
+        );
+
+        assert_eq!(
+            detect_preferred_host(&dir_path, "vite"),
+            Some("myapp.local".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_preferred_host_vite_allowed_hosts() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default { server: { host: '0.0.0.0', allowedHosts: ['.myapp.local', 'localhost'] } }",
+        );
+
+        assert_eq!(
+            detect_preferred_host(&dir_path, "vite"),
+            Some("myapp.local".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_preferred_host_loopback_only() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default { server: { host: true, allowedHosts: true, port: 5173 } }",
+        );
+
+        assert_eq!(detect_preferred_host(&dir_path, "vite"), None);
+    }
+
+    #[test]
+    fn test_detect_https_from_dev_script() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(&dir_path, "next.config.js", "module.exports = {}");
+        create_temp_file(
+            &dir_path,
+            "package.json",
+            r#"{"scripts": {"dev": "next dev --experimental-https"}}"#,
+        );
+
+        assert_eq!(detect_https(&dir_path, "nextjs"), Some(true));
+    }
+
+    #[test]
+    fn test_detect_https_no_framework() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(&dir_path, "package.json", r#"{}"#);
+
+        assert_eq!(detect_https(&dir_path, "node"), None);
+    }
+
+    #[test]
+    fn test_extract_https_from_config_ignores_comments_and_identifiers() {
+        assert_eq!(extract_https_from_config("// https: true"), None);
+        assert_eq!(
+            extract_https_from_config("const useHttps: boolean = true"),
+            None
+        );
+        assert_eq!(
+            extract_https_from_config("plugins: [basicSsl()]"),
+            Some(true)
+        );
+        assert_eq!(
+            extract_https_from_config("\"https\": { \"key\": \"k.pem\" }"),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_detect_proxy_targets_vite() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            r#"export default defineConfig({
+  server: {
+    port: 5173,
+    proxy: {
+      '/api': {
+        target: 'http://localhost:8080',
+        changeOrigin: true,
+      },
+    },
+  },
+})"#,
+        );
+
+        assert_eq!(
+            detect_proxy_targets(&dir_path),
+            vec!["http://localhost:8080".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_detect_proxy_targets_next_rewrites_and_package_json() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "next.config.js",
+            r#"module.exports = {
+  images: { domains: ['https://cdn.example.com'] },
+  async rewrites() {
+    return [
+      { source: '/api/:path*', destination: 'http://localhost:4000/:path*' },
+      { source: '/old', destination: '/new' },
+    ]
+  },
+}"#,
+        );
+        create_temp_file(
+            &dir_path,
+            "package.json",
+            r#"{"proxy": "http://localhost:4000/:path*"}"#,
+        );
+
+        assert_eq!(
+            detect_proxy_targets(&dir_path),
+            vec!["http://localhost:4000/:path*".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_detect_proxy_targets_none_configured() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            &dir_path,
+            "vite.config.ts",
+            "export default { server: { port: 5173 } }",
+        );
+
+        assert!(detect_proxy_targets(&dir_path).is_empty());
+    }
+
+    #[test]
+    fn test_port_warnings_known_collisions() {
+        let airplay = port_warnings(5000);
+        assert_eq!(airplay.len(), 1);
+        assert!(airplay[0].contains("AirPlay"));
+
+        assert!(port_warnings(7000)[0].contains("Control Center"));
+        assert!(port_warnings(5432)[0].contains("PostgreSQL"));
+    }
+
+    #[test]
+    fn test_port_warnings_unsafe_ranges() {
+        assert!(port_warnings(80)[0].contains("privileged"));
+        assert!(port_warnings(50000)[0].contains("ephemeral"));
+        assert_eq!(port_warnings(0).len(), 1);
+    }
+
+    #[test]
+    fn test_port_warnings_clean_port() {
+        assert!(port_warnings(5173).is_empty());
+        assert!(port_warnings(4321).is_empty());
+    }
+
+    #[test]
+    fn test_ready_pattern_for_frameworks() {
+        assert_eq!(ready_pattern_for("vite"), Some("Local:".to_string()));
+        assert_eq!(ready_pattern_for("sveltekit"), Some("Local:".to_string()));
+        assert_eq!(
+            ready_pattern_for("nextjs"),
+            Some("Ready in|ready - started server".to_string())
+        );
+        assert_eq!(ready_pattern_for("node"), None);
+        assert_eq!(escape_regex("a.b|c"), "a\\.b\\|c");
+    }
+
+    #[test]
+    fn test_is_ready_line_matches_log_output() {
+        // Vite bolds the label, so the raw line contains ANSI codes
+        assert!(is_ready_line(
+            "vite",
+            "  \u{1b}[32m➜\u{1b}[39m  \u{1b}[1mLocal\u{1b}[22m:   \u{1b}[36mhttp://localhost:5173/\u{1b}[39m"
+        ));
+        assert!(is_ready_line("nextjs", " ✓ Ready in 1843ms"));
+        assert!(!is_ready_line("vite", "  VITE v5.0.0  building..."));
+        assert!(!is_ready_line("node", "Local: http://localhost:3000"));
+    }
+
+    #[test]
+    fn test_extract_banner_port() {
+        assert_eq!(
+            extract_banner_port(
+                "  \u{1b}[32m➜\u{1b}[39m  \u{1b}[1mLocal\u{1b}[22m:   \u{1b}[36mhttp://localhost:\u{1b}[1m4173\u{1b}[22m/\u{1b}[39m"
+            ),
+            Some(4173)
+        );
+        assert_eq!(
+            extract_banner_port("   - Local:        http://127.0.0.1:3000"),
+            Some(3000)
+        );
+        assert_eq!(
+            extract_banner_port("Proxying /api to https://api.example.com:8443"),
+            None
+        );
+        assert_eq!(extract_banner_port("Network: use --host to expose"), None);
+    }
 }
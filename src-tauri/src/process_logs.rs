@@ -1,8 +1,189 @@
-use std::io::{BufRead, BufReader};
-use std::process::{Command as StdCommand, Stdio};
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::process::{Command as StdCommand, ExitStatus, Stdio};
 use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
 use tauri::{AppHandle, Emitter};
 
+// Environment captured from the user's login shell by prewarm_shell, reused for the session
+static SHELL_ENV_CACHE: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+// Processes whose log streaming the user paused; their output is still recorded in log_history
+static PAUSED_LOG_STREAMS: OnceLock<Mutex<HashSet<u32>>> = OnceLock::new();
+
+fn get_paused_log_streams() -> &'static Mutex<HashSet<u32>> {
+    PAUSED_LOG_STREAMS.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+// PIDs killed by restart_process; their process-exit is suppressed so it can't
+// arrive after the replacement process has started
+static RESTARTED_PIDS: OnceLock<Mutex<HashSet<u32>>> = OnceLock::new();
+
+fn get_restarted_pids() -> &'static Mutex<HashSet<u32>> {
+    RESTARTED_PIDS.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Removes `pid` from the restarted set, returning whether it was there
+fn take_restarted_pid(pid: u32) -> bool {
+    get_restarted_pids()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(&pid)
+}
+
+/// Whether output lines of a process should be emitted to the frontend
+fn is_log_stream_enabled(pid: u32) -> bool {
+    !get_paused_log_streams()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .contains(&pid)
+}
+
+fn set_log_stream_enabled(pid: u32, enabled: bool) {
+    let mut paused = get_paused_log_streams()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if enabled {
+        paused.remove(&pid);
+    } else {
+        paused.insert(pid);
+    }
+}
+
+/// Runs `emit` for an output line of `pid` unless its stream is paused
+///
+/// Returns whether the reader should keep going: false only when `emit` fails.
+fn emit_unless_paused(pid: u32, emit: impl FnOnce() -> bool) -> bool {
+    !is_log_stream_enabled(pid) || emit()
+}
+
+/// Stops emitting the stdout/stderr events of a project's running processes
+/// until resume_log_stream
+///
+/// Lines are still recorded in the log history, so they can be exported later.
+/// Processes started afterwards (e.g. by a restart) stream as usual.
+#[tauri::command]
+pub fn pause_log_stream(project_path: String) {
+    for pid in crate::process::project_pids(&project_path) {
+        set_log_stream_enabled(pid, false);
+    }
+}
+
+/// Resumes emitting a project's stdout/stderr events after pause_log_stream
+#[tauri::command]
+pub fn resume_log_stream(project_path: String) {
+    for pid in crate::process::project_pids(&project_path) {
+        set_log_stream_enabled(pid, true);
+    }
+}
+
+// How long a partial line (an interactive prompt, a progress bar) waits for more
+// output before it's emitted on its own
+const PARTIAL_LINE_FLUSH: Duration = Duration::from_millis(150);
+
+// How long process-exit waits for the readers to flush the remaining output;
+// a backgrounded grandchild can keep the pipes open past the exit
+const OUTPUT_FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Splits raw process output into lines
+///
+/// `\n`, `\r\n` and a lone `\r` (a progress bar redrawing its line) all end a
+/// line. Bytes after the last terminator are held until more output or a flush.
+/// Each line carries the time its first byte was read from the pipe.
+#[derive(Default)]
+struct LineBuffer {
+    pending: Vec<u8>,
+    // When the first pending byte was read
+    pending_since: Option<SystemTime>,
+    // The last byte was `\r`, so a following `\n` completes a `\r\n`
+    after_cr: bool,
+}
+
+impl LineBuffer {
+    fn push(&mut self, chunk: &[u8], read_at: SystemTime) -> Vec<(SystemTime, String)> {
+        let mut lines = Vec::new();
+        for &byte in chunk {
+            let after_cr = std::mem::replace(&mut self.after_cr, byte == b'\r');
+            match byte {
+                b'\n' if after_cr => {}
+                // Redraws usually start with `\r`, so an empty line isn't worth emitting
+                b'\r' if self.pending.is_empty() => {}
+                b'\n' | b'\r' => lines.push(self.take(read_at)),
+                _ => {
+                    self.pending_since.get_or_insert(read_at);
+                    self.pending.push(byte);
+                }
+            }
+        }
+        lines
+    }
+
+    fn flush(&mut self) -> Option<(SystemTime, String)> {
+        (!self.pending.is_empty()).then(|| self.take(SystemTime::now()))
+    }
+
+    fn take(&mut self, read_at: SystemTime) -> (SystemTime, String) {
+        let read_at = self.pending_since.take().unwrap_or(read_at);
+        let line = String::from_utf8_lossy(&std::mem::take(&mut self.pending)).into_owned();
+        (read_at, line)
+    }
+}
+
+/// Reads a pipe to the end, passing each line and the time it was read to `on_line`
+///
+/// Unlike `BufRead::lines`, output without a trailing newline isn't held back:
+/// it's passed on once the pipe has been idle for PARTIAL_LINE_FLUSH, and when
+/// the pipe closes. Reading stops early if `on_line` returns false.
+fn read_lines<R, F>(mut pipe: R, mut on_line: F)
+where
+    R: Read + Send + 'static,
+    F: FnMut(SystemTime, String) -> bool,
+{
+    // Reads block, so they happen on their own thread and the idle timeout is
+    // taken on the channel. Chunks are stamped here, as they come off the pipe.
+    let (chunk_tx, chunk_rx) = mpsc::channel::<(SystemTime, Vec<u8>)>();
+    std::thread::spawn(move || {
+        let mut chunk = [0u8; 8192];
+        loop {
+            match pipe.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => {
+                    if chunk_tx
+                        .send((SystemTime::now(), chunk[..n].to_vec()))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(_) => break, // Pipe closed or error, exit thread
+            }
+        }
+    });
+
+    let mut buffer = LineBuffer::default();
+    loop {
+        let lines = match chunk_rx.recv_timeout(PARTIAL_LINE_FLUSH) {
+            Ok((read_at, chunk)) => buffer.push(&chunk, read_at),
+            Err(RecvTimeoutError::Timeout) => buffer.flush().into_iter().collect(),
+            Err(RecvTimeoutError::Disconnected) => {
+                if let Some((read_at, line)) = buffer.flush() {
+                    on_line(read_at, line);
+                }
+                return;
+            }
+        };
+        for (read_at, line) in lines {
+            if !on_line(read_at, line) {
+                return;
+            }
+        }
+    }
+}
+
 /// Escapes a string for safe use in single-quoted shell context
 /// 
 /// This function properly escapes single quotes using the POSIX-compliant method:
@@ -34,8 +215,120 @@ fn shell_quote(s: &str) -> String {
     format!("'{}'", escape_shell_single_quote(s))
 }
 
+/// Prefix for the copies of project env vars that shell scripts re-export by name
+const ENV_PASSTHROUGH_PREFIX: &str = "RUNSTACK_ENV_";
+
+/// Copies `env` under ENV_PASSTHROUGH_PREFIX, to be set on the shell process
+fn passthrough_env(env: &HashMap<String, String>) -> impl Iterator<Item = (String, &String)> {
+    env.iter()
+        .map(|(name, value)| (format!("{}{}", ENV_PASSTHROUGH_PREFIX, name), value))
+}
+
+/// Builds statements that re-export the given environment variables, in name order
+///
+/// Values are never written into the script: each variable is copied from its
+/// prefixed passthrough variable (see passthrough_env), so the shell's own
+/// quoting rules don't matter. Names must already be validated.
+fn env_exports(shell: &str, env: &HashMap<String, String>) -> String {
+    let mut names: Vec<&String> = env.keys().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| match shell {
+            "fish" => format!("set -gx {} ${}{}; ", name, ENV_PASSTHROUGH_PREFIX, name),
+            "csh" | "tcsh" => format!("setenv {} ${}{}:q; ", name, ENV_PASSTHROUGH_PREFIX, name),
+            _ => format!("export {}=\"${}{}\"; ", name, ENV_PASSTHROUGH_PREFIX, name),
+        })
+        .collect()
+}
+
+/// Quotes a string for PowerShell; nothing is expanded inside single quotes
+fn powershell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// Characters cmd.exe still interprets inside double quotes, or that end the quoting
+const CMD_UNSAFE_CHARS: &[char] = &['%', '!', '^', '"'];
+
+/// Quotes a string for cmd.exe
+///
+/// cmd.exe has no quoting that disables `%VAR%`/`!VAR!` expansion, so strings
+/// containing CMD_UNSAFE_CHARS are rejected rather than quoted. Command chaining
+/// and redirection characters are already rejected by validate_command_args.
+fn cmd_quote(s: &str) -> Result<String, crate::error::AppError> {
+    match s.chars().find(|c| CMD_UNSAFE_CHARS.contains(c)) {
+        Some(c) => Err(crate::error::AppError::CommandError(format!(
+            "'{}' contains '{}', which cmd.exe can't pass through safely",
+            s, c
+        ))),
+        None => Ok(format!("\"{}\"", s)),
+    }
+}
+
+/// Builds the script a shell runs: its config `source_command`, then the command
+///
+/// POSIX shells get `env` exported and a `cd` into `cwd` in the script.
+/// Other shells (PowerShell, cmd.exe, nushell) only get the command line:
+/// spawn_with_logs sets the directory and variables on the process, which
+/// sidesteps their assignment syntax. Fails when the command line can't be
+/// quoted for the shell (see cmd_quote).
+fn shell_script(
+    shell_path: &str,
+    source_command: &str,
+    env: &HashMap<String, String>,
+    cwd: &str,
+    command: &str,
+    args: &[String],
+) -> Result<String, crate::error::AppError> {
+    let words = || std::iter::once(command).chain(args.iter().map(String::as_str));
+    let command_line = |quote: fn(&str) -> String| words().map(quote).collect::<Vec<_>>().join(" ");
+
+    let shell = shell_name(shell_path);
+    Ok(match shell {
+        // `&` runs the quoted command name; the exit code is passed on explicitly
+        "pwsh" | "powershell" => {
+            format!("& {}; exit $LASTEXITCODE", command_line(powershell_quote))
+        }
+        "cmd" => words()
+            .map(cmd_quote)
+            .collect::<Result<Vec<_>, _>>()?
+            .join(" "),
+        // Exports come after the shell config is sourced so project values win
+        "sh" | "dash" | "bash" | "zsh" | "fish" | "ksh" | "csh" | "tcsh" => format!(
+            "{}; {}cd {} && {}",
+            source_command,
+            env_exports(shell, env),
+            shell_quote(cwd),
+            command_line(shell_quote)
+        ),
+        _ => command_line(shell_quote),
+    })
+}
+
+/// Passes the script to the shell as its last argument
+#[cfg(unix)]
+fn push_script(shell: &mut StdCommand, _shell_path: &str, script: &str) {
+    shell.arg(script);
+}
+
+/// Passes the script to the shell as its last argument
+#[cfg(windows)]
+fn push_script(shell: &mut StdCommand, shell_path: &str, script: &str) {
+    use std::os::windows::process::CommandExt;
+
+    if shell_name(shell_path) == "cmd" {
+        // With /S, cmd.exe strips the outer quotes and runs the rest as written;
+        // the default argument quoting would escape the inner ones
+        shell.raw_arg(format!("\"{}\"", script));
+    } else {
+        shell.arg(script);
+    }
+}
+
 /// Detects the user's preferred shell and returns a list of shells to try
 /// Priority: 1) User's $SHELL, 2) Platform defaults, 3) Common alternatives
+#[cfg(unix)]
 fn get_shells_to_try() -> Vec<(String, String)> {
     let mut shells = Vec::new();
     
@@ -111,12 +404,222 @@ fn get_shells_to_try() -> Vec<(String, String)> {
     shells
 }
 
+/// Returns the shells to try on Windows, PowerShell first and cmd.exe as a fallback
+///
+/// There's no $SHELL or rc file to source: processes inherit the user
+/// environment, and PowerShell loads the user's profile itself.
+#[cfg(windows)]
+fn get_shells_to_try() -> Vec<(String, String)> {
+    ["pwsh.exe", "powershell.exe", "cmd.exe"]
+        .iter()
+        .map(|shell| (shell.to_string(), String::new()))
+        .collect()
+}
+
+/// Returns a shell's name without its directory or `.exe` extension
+fn shell_name(shell_path: &str) -> &str {
+    let name = shell_path.rsplit(['/', '\\']).next().unwrap_or(shell_path);
+    name.strip_suffix(".exe").unwrap_or(name)
+}
+
+/// Returns the command that loads a shell's config, as get_shells_to_try would
+///
+/// Shells it doesn't know load nothing extra.
+fn get_source_command(shell_path: &str) -> String {
+    get_shells_to_try()
+        .into_iter()
+        .find(|(path, _)| shell_name(path) == shell_name(shell_path))
+        .map(|(_, source_command)| source_command)
+        .unwrap_or_else(|| "true".to_string())
+}
+
+/// Returns the flags used to run a command string with the given shell
+fn get_shell_flags(shell_path: &str) -> Vec<&'static str> {
+    // Extract shell name from path to avoid substring matching issues (e.g., "fish" contains "sh")
+    let shell_name = shell_name(shell_path);
+    if shell_name == "pwsh" || shell_name == "powershell" {
+        vec!["-NoLogo", "-NonInteractive", "-Command"]
+    } else if shell_name == "cmd" {
+        // /S keeps the quotes inside the command line as written
+        vec!["/D", "/S", "/C"]
+    } else if shell_name == "fish" {
+        // Fish doesn't support -l (login), use -c instead
+        vec!["-c"]
+    } else if shell_name == "sh" {
+        // POSIX sh doesn't support -l, use -c
+        vec!["-c"]
+    } else {
+        // zsh, bash, csh, tcsh, ksh support -l (login shell)
+        vec!["-l", "-c"]
+    }
+}
+
+/// Parses the NUL-separated output of `env -0` into a map
+#[cfg(unix)]
+fn parse_env_output(output: &[u8]) -> HashMap<String, String> {
+    String::from_utf8_lossy(output)
+        .split('\0')
+        .filter_map(|entry| {
+            let (key, value) = entry.split_once('=')?;
+            // Skip the leftovers of multi-line values and empty entries
+            if key.is_empty() || key.contains(char::is_whitespace) {
+                return None;
+            }
+            Some((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Captures the environment of the user's login shell after sourcing its config
+///
+/// Tries the same shells as spawn_process_with_logs, in the same order, and
+/// returns the environment from the first one that runs successfully.
+#[cfg(unix)]
+pub fn get_effective_env() -> Result<HashMap<String, String>, crate::error::AppError> {
+    let mut last_error = None;
+
+    for (shell_path, source_command) in get_shells_to_try() {
+        let shell_command = format!("{}; env -0", source_command);
+
+        match StdCommand::new(&shell_path)
+            .args(get_shell_flags(&shell_path))
+            .arg(&shell_command)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+        {
+            Ok(output) if output.status.success() => {
+                let env = parse_env_output(&output.stdout);
+                if env.contains_key("PATH") {
+                    return Ok(env);
+                }
+                last_error = Some(format!("{} did not report a PATH", shell_path));
+            }
+            Ok(output) => {
+                last_error = Some(format!("{} exited with {}", shell_path, output.status));
+            }
+            Err(e) => {
+                last_error = Some(format!("Failed to spawn {}: {}", shell_path, e));
+            }
+        }
+    }
+
+    Err(crate::error::AppError::ProcessError(
+        last_error.unwrap_or_else(|| "No suitable shell found".to_string())
+    ))
+}
+
+/// Returns the user environment on Windows, which the app already inherits
+#[cfg(windows)]
+pub fn get_effective_env() -> Result<HashMap<String, String>, crate::error::AppError> {
+    Ok(env::vars().collect())
+}
+
+/// Returns the shell environment cached in `cache`, loading it with `load` on first use
+fn get_or_load_shell_env<F>(
+    cache: &OnceLock<HashMap<String, String>>,
+    load: F,
+) -> Result<&HashMap<String, String>, crate::error::AppError>
+where
+    F: FnOnce() -> Result<HashMap<String, String>, crate::error::AppError>,
+{
+    if let Some(env) = cache.get() {
+        return Ok(env);
+    }
+
+    let env = load()?;
+    // If another thread won the race, its environment is kept and ours is dropped
+    Ok(cache.get_or_init(|| env))
+}
+
+/// Sources the user's shell config once and caches the resulting environment
+///
+/// Later spawns inject the cached environment directly instead of starting a
+/// login shell and re-running the rc files, which makes dev server starts
+/// noticeably faster for users with heavy shell configs (nvm in particular).
+pub fn prewarm_shell() -> Result<(), crate::error::AppError> {
+    get_or_load_shell_env(&SHELL_ENV_CACHE, get_effective_env).map(|_| ())
+}
+
+/// Returns the shells a command is run through, in the order they're tried
+///
+/// When the shell environment was prewarmed, commands run through plain sh
+/// with the cached environment instead of sourcing the user's config again.
+fn get_spawn_shells(cached_env: Option<&HashMap<String, String>>) -> Vec<(String, String)> {
+    match cached_env {
+        Some(_) if cfg!(unix) => vec![("/bin/sh".to_string(), "true".to_string())],
+        _ => get_shells_to_try(),
+    }
+}
+
+/// Runs a command to completion through the user's shell and captures its output
+///
+/// Uses the same validation, shells and environment as spawn_process_with_logs,
+/// for short-lived commands whose output is parsed rather than streamed. A
+/// non-zero exit is returned in the output, not as an error.
+pub fn run_captured(
+    command: &str,
+    args: &[String],
+    cwd: &str,
+) -> Result<std::process::Output, crate::error::AppError> {
+    crate::validation::validate_command(command)?;
+    crate::validation::validate_command_args(args)?;
+    let validated_path = crate::validation::validate_directory_path(cwd)?;
+    let validated_path_str = validated_path.to_string_lossy();
+
+    let cached_env = SHELL_ENV_CACHE.get();
+    let mut last_error = None;
+
+    for (shell_path, source_command) in get_spawn_shells(cached_env) {
+        let shell_command = match shell_script(
+            &shell_path,
+            &source_command,
+            &HashMap::new(),
+            &validated_path_str,
+            command,
+            args,
+        ) {
+            Ok(script) => script,
+            Err(e) => {
+                last_error = Some(format!("Can't run with {}: {}", shell_path, e));
+                continue;
+            }
+        };
+
+        let mut shell = StdCommand::new(&shell_path);
+        shell.args(get_shell_flags(&shell_path));
+        push_script(&mut shell, &shell_path, &shell_command);
+        shell.stdin(Stdio::null());
+        if let Some(env) = cached_env {
+            shell.envs(env);
+        }
+        #[cfg(windows)]
+        shell.current_dir(&validated_path);
+
+        match shell.output() {
+            Ok(output) => return Ok(output),
+            Err(e) => last_error = Some(format!("Failed to spawn with {}: {}", shell_path, e)),
+        }
+    }
+
+    Err(crate::error::AppError::ProcessError(last_error.unwrap_or_else(|| {
+        format!("Failed to run '{}': No suitable shell found", command)
+    })))
+}
+
 /// Spawns a process and streams its stdout/stderr to the frontend via events
 /// 
 /// Note: Once spawned, the child process handle is moved into background threads
 /// and cannot be accessed or killed through this function's return value. The process
 /// must be managed through the PID returned by this function using external tools
 /// or the kill_process_tree function.
+///
+/// `env` sets extra environment variables for the command; they're applied
+/// after the shell config is sourced, so they override it.
+///
+/// `shell_override` runs the command through exactly that shell (an absolute
+/// path, invoked with `-c`) instead of the detected ones. There's no fallback:
+/// if it can't be spawned, an error is returned.
 #[tauri::command]
 pub async fn spawn_process_with_logs(
     app: AppHandle,
@@ -124,12 +627,224 @@ pub async fn spawn_process_with_logs(
     args: Vec<String>,
     cwd: String,
     project_path: String,
+    env: Option<HashMap<String, String>>,
+    shell_override: Option<String>,
 ) -> Result<u32, crate::error::AppError> {
+    spawn_with_logs(
+        app,
+        command,
+        args,
+        cwd,
+        project_path,
+        SpawnOptions {
+            env: env.unwrap_or_default(),
+            shell: shell_override,
+        },
+        |_| {},
+    )
+}
+
+/// Restarts a project's process: kills its tracked process trees and spawns the command again
+///
+/// `process-restart` is emitted (with the killed PIDs) before respawning; the
+/// killed processes don't emit process-exit. The new process streams through
+/// the same process-stdout/process-stderr events, so listeners keyed on the
+/// project path don't need to re-subscribe. `env` and `shell_override` work as
+/// in spawn_process_with_logs, so pass the ones the process was started with.
+#[tauri::command]
+pub async fn restart_process(
+    app: AppHandle,
+    command: String,
+    args: Vec<String>,
+    cwd: String,
+    project_path: String,
+    env: Option<HashMap<String, String>>,
+    shell_override: Option<String>,
+) -> Result<u32, crate::error::AppError> {
+    let env = env.unwrap_or_default();
+    // Validate before killing so a rejected command doesn't leave the project stopped
+    crate::validation::validate_command(&command)?;
+    crate::validation::validate_command_args(&args)?;
+    crate::validation::validate_env_vars(&env)?;
+    crate::validation::validate_directory_path(&cwd)?;
+    if let Some(shell) = &shell_override {
+        crate::validation::validate_shell_path(shell)?;
+    }
+
+    let mut previous_pids = Vec::new();
+    for pid in crate::process::project_pids(&project_path) {
+        // Marked before the kill so the wait thread can't emit process-exit first
+        get_restarted_pids()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(pid);
+        match crate::process::kill_process_tree(pid, crate::process::DEFAULT_KILL_DEPTH) {
+            Ok(_) => previous_pids.push(pid),
+            // Exited on its own since it was listed
+            Err(crate::error::AppError::NotFound(_)) => {
+                take_restarted_pid(pid);
+            }
+            Err(e) => {
+                take_restarted_pid(pid);
+                return Err(e);
+            }
+        }
+    }
+
+    // Emit restart event, ignore errors if app is shutting down
+    let _ = app.emit(
+        "process-restart",
+        serde_json::json!({
+            "projectPath": project_path,
+            "previousPids": previous_pids
+        }),
+    );
+
+    spawn_with_logs(
+        app,
+        command,
+        args,
+        cwd,
+        project_path,
+        SpawnOptions {
+            env,
+            shell: shell_override,
+        },
+        |_| {},
+    )
+}
+
+/// What the stdout and stderr readers of one process share
+#[derive(Clone)]
+struct ReaderContext {
+    app: AppHandle,
+    project_path: String,
+    pid: u32,
+    framework: &'static str,
+    ready_emitted: Arc<AtomicBool>,
+    port_detected: Arc<AtomicBool>,
+    flushed_tx: mpsc::Sender<()>,
+}
+
+/// Streams one of a process's pipes to the frontend on its own thread
+///
+/// Each line is recorded in the log history and emitted as `event` unless the
+/// stream is paused. The first ready banner and local URL seen on either pipe
+/// also emit process-ready and port-detected.
+fn spawn_reader<R>(
+    pipe: R,
+    stream: crate::log_history::LogStream,
+    event: &'static str,
+    context: ReaderContext,
+) where
+    R: Read + Send + 'static,
+{
+    let ReaderContext {
+        app,
+        project_path,
+        pid,
+        framework,
+        ready_emitted,
+        port_detected,
+        flushed_tx,
+    } = context;
+
+    std::thread::Builder::new()
+        .name(format!("{}-{}", event, pid))
+        .spawn(move || {
+            // Dropped once the remaining output has been emitted
+            let _flushed_tx = flushed_tx;
+            read_lines(pipe, |read_at, line| {
+                // Keep a copy so the logs can be exported after the process exits;
+                // the timestamp is when the line came off the pipe
+                let timestamp = crate::log_history::record(&project_path, stream, &line, read_at);
+
+                if !ready_emitted.load(Ordering::Relaxed)
+                    && crate::port::is_ready_line(framework, &line)
+                    && !ready_emitted.swap(true, Ordering::Relaxed)
+                {
+                    let _ = app.emit(
+                        "process-ready",
+                        serde_json::json!({
+                            "projectPath": project_path.clone(),
+                            "pid": pid
+                        }),
+                    );
+                }
+
+                let banner_port = if port_detected.load(Ordering::Relaxed) {
+                    None
+                } else {
+                    crate::port::extract_banner_port(&line)
+                };
+                if let Some(port) =
+                    banner_port.filter(|_| !port_detected.swap(true, Ordering::Relaxed))
+                {
+                    let _ = app.emit(
+                        "port-detected",
+                        serde_json::json!({
+                            "projectPath": project_path.clone(),
+                            "pid": pid,
+                            "port": port
+                        }),
+                    );
+                }
+
+                // If emit fails, it means the app is shutting down, so exit the thread
+                emit_unless_paused(pid, || {
+                    app.emit(
+                        event,
+                        serde_json::json!({
+                            "projectPath": project_path.clone(),
+                            "content": line,
+                            "timestamp": timestamp
+                        }),
+                    )
+                    .is_ok()
+                })
+            });
+        })
+        .expect("Failed to spawn output reader thread");
+}
+
+/// Optional settings for spawn_with_logs
+#[derive(Debug, Default)]
+pub struct SpawnOptions {
+    /// Extra environment variables, applied after the shell config is sourced
+    pub env: HashMap<String, String>,
+    /// Absolute path of the shell to use instead of the detected ones (no fallback)
+    pub shell: Option<String>,
+}
+
+/// Spawns a process with log streaming, calling `on_exit` once it has exited
+///
+/// This is the machinery behind spawn_process_with_logs for commands that need
+/// to react to completion (e.g. emit their own event). `on_exit` receives the
+/// exit status, or None if waiting on the process failed.
+pub fn spawn_with_logs<F>(
+    app: AppHandle,
+    command: String,
+    args: Vec<String>,
+    cwd: String,
+    project_path: String,
+    options: SpawnOptions,
+    on_exit: F,
+) -> Result<u32, crate::error::AppError>
+where
+    F: FnOnce(Option<ExitStatus>) + Send + 'static,
+{
+    let SpawnOptions { env, shell: shell_override } = options;
+
     // SECURITY: Validate command and arguments before processing
     // This prevents command injection by ensuring only whitelisted commands
     // and safe arguments are used.
     crate::validation::validate_command(&command)?;
     crate::validation::validate_command_args(&args)?;
+    crate::validation::validate_env_vars(&env)?;
+    let shell_override = shell_override
+        .as_deref()
+        .map(crate::validation::validate_shell_path)
+        .transpose()?;
     
     let validated_path = crate::validation::validate_directory_path(&cwd)?;
     
@@ -145,26 +860,18 @@ pub async fn spawn_process_with_logs(
     // 3. Properly escaping all user-controlled data with single quotes
     // 4. Using single quotes which prevent shell interpretation of special characters
     
-    // Escape and quote each argument using robust escaping
-    let args_str: String = args
-        .iter()
-        .map(|arg| shell_quote(arg))
-        .collect::<Vec<_>>()
-        .join(" ");
-    
-    // Get list of shells to try (user's shell first, then fallbacks)
-    let shells = get_shells_to_try();
+    let cached_env = SHELL_ENV_CACHE.get();
+
+    // Get list of shells to try (user's shell first, then fallbacks), or just
+    // the one the caller chose
+    let shells = match &shell_override {
+        Some(shell_path) => vec![(shell_path.clone(), get_source_command(shell_path))],
+        None => get_spawn_shells(cached_env),
+    };
     
     // Track the preferred shell (first in list) to detect fallback usage
     let preferred_shell = shells.first().map(|(path, _)| path.clone());
     
-    // Build the command to execute (cd to directory and run the command)
-    // SECURITY: All user-controlled data (path, command, args) is properly quoted
-    // Single quotes prevent shell interpretation, and we've validated inputs above
-    let quoted_path = shell_quote(&validated_path_str);
-    let quoted_command = shell_quote(&command);
-    let command_part = format!("cd {} && {} {}", quoted_path, quoted_command, args_str);
-    
     // Try each shell until one works
     // We source shell config files to ensure all version managers (FNM, NVM, Volta, asdf) are loaded
     let mut child = None;
@@ -173,29 +880,44 @@ pub async fn spawn_process_with_logs(
     let mut preferred_shell_failed = false;
     
     for (index, (shell_path, source_command)) in shells.iter().enumerate() {
-        // Construct full shell command: source config + execute command
-        let shell_command = format!("{}; {}", source_command, command_part);
+        // Construct full shell command: source config + execute command (cd to directory and run it)
+        // SECURITY: All user-controlled data (path, command, args) is properly quoted
+        // for the shell, and we've validated inputs above
+        let shell_command = match shell_script(
+            shell_path,
+            source_command,
+            &env,
+            &validated_path_str,
+            &command,
+            &args,
+        ) {
+            Ok(script) => script,
+            Err(e) => {
+                last_error = Some(format!("Can't run with {}: {}", shell_path, e));
+                if index == 0 {
+                    preferred_shell_failed = true;
+                }
+                continue;
+            }
+        };
         
         // Determine shell flags based on shell type
-        // Extract shell name from path to avoid substring matching issues (e.g., "fish" contains "sh")
-        let shell_name = shell_path.split('/').last().unwrap_or(shell_path);
-        let shell_flags = if shell_name == "fish" {
-            // Fish doesn't support -l (login), use -c instead
-            vec!["-c"]
-        } else if shell_name == "sh" {
-            // POSIX sh doesn't support -l, use -c
-            vec!["-c"]
-        } else {
-            // zsh, bash, csh, tcsh, ksh support -l (login shell)
-            vec!["-l", "-c"]
+        let shell_flags = match shell_override {
+            Some(_) => vec!["-c"],
+            None => get_shell_flags(shell_path),
         };
         
-        match StdCommand::new(shell_path)
-            .args(&shell_flags)
-            .arg(&shell_command)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
+        let mut shell = StdCommand::new(shell_path);
+        shell.args(&shell_flags);
+        push_script(&mut shell, shell_path, &shell_command);
+        shell.stdout(Stdio::piped()).stderr(Stdio::piped());
+        if let Some(env) = cached_env {
+            shell.envs(env);
+        }
+        // Shells whose script can't cd or export (see shell_script) rely on these
+        shell.current_dir(&validated_path).envs(&env).envs(passthrough_env(&env));
+        
+        match shell.spawn()
         {
             Ok(c) => {
                 child = Some(c);
@@ -258,66 +980,41 @@ pub async fn spawn_process_with_logs(
     }
 
     let pid = child.id();
+    let command_line = format!("{} {}", command, args.join(" ")).trim().to_string();
+    crate::process::register_process(pid, &project_path, Some(command_line), false);
+
+    // Each reader holds a sender; process-exit waits for all of them to drop
+    let (flushed_tx, flushed_rx) = mpsc::channel::<()>();
+    // Dev servers print a banner once they accept requests; emit process-ready
+    // the first time either stream shows it, and port-detected for the first
+    // local URL it prints
+    let context = ReaderContext {
+        app: app.clone(),
+        project_path: project_path.clone(),
+        pid,
+        framework: crate::detection::detect_framework_from_path(&validated_path),
+        ready_emitted: Arc::new(AtomicBool::new(false)),
+        port_detected: Arc::new(AtomicBool::new(false)),
+        flushed_tx,
+    };
 
-    // Handle stdout
     if let Some(stdout) = child.stdout.take() {
-        let app_clone = app.clone();
-        let project_path_clone = project_path.clone();
-        let reader = BufReader::new(stdout);
-
-        std::thread::Builder::new()
-            .name(format!("process-stdout-{}", pid))
-            .spawn(move || {
-                for line in reader.lines() {
-                    match line {
-                        Ok(line) => {
-                            // If emit fails, it means the app is shutting down, so exit the thread
-                            if app_clone.emit(
-                                "process-stdout",
-                                serde_json::json!({
-                                    "projectPath": project_path_clone.clone(),
-                                    "content": line
-                                }),
-                            ).is_err() {
-                                break;
-                            }
-                        }
-                        Err(_) => break, // Pipe closed or error, exit thread
-                    }
-                }
-            })
-            .expect("Failed to spawn stdout reader thread");
+        spawn_reader(
+            stdout,
+            crate::log_history::LogStream::Stdout,
+            "process-stdout",
+            context.clone(),
+        );
     }
-
-    // Handle stderr
     if let Some(stderr) = child.stderr.take() {
-        let app_clone = app.clone();
-        let project_path_clone = project_path.clone();
-        let reader = BufReader::new(stderr);
-
-        std::thread::Builder::new()
-            .name(format!("process-stderr-{}", pid))
-            .spawn(move || {
-                for line in reader.lines() {
-                    match line {
-                        Ok(line) => {
-                            // If emit fails, it means the app is shutting down, so exit the thread
-                            if app_clone.emit(
-                                "process-stderr",
-                                serde_json::json!({
-                                    "projectPath": project_path_clone.clone(),
-                                    "content": line
-                                }),
-                            ).is_err() {
-                                break;
-                            }
-                        }
-                        Err(_) => break, // Pipe closed or error, exit thread
-                    }
-                }
-            })
-            .expect("Failed to spawn stderr reader thread");
+        spawn_reader(
+            stderr,
+            crate::log_history::LogStream::Stderr,
+            "process-stderr",
+            context.clone(),
+        );
     }
+    drop(context);
 
     // Handle process exit
     let app_clone = app.clone();
@@ -325,7 +1022,15 @@ pub async fn spawn_process_with_logs(
     std::thread::Builder::new()
         .name(format!("process-wait-{}", pid))
         .spawn(move || {
-            match child.wait() {
+            let status = child.wait();
+            crate::process::unregister_process(pid);
+            set_log_stream_enabled(pid, true);
+            // Emit the output still buffered by the readers before process-exit
+            let _ = flushed_rx.recv_timeout(OUTPUT_FLUSH_TIMEOUT);
+            let exit_status = status.as_ref().ok().copied();
+            match status {
+                // Killed by restart_process, which already emitted process-restart
+                Ok(_) if take_restarted_pid(pid) => {}
                 Ok(_status) => {
                     // Emit exit event, ignore errors if app is shutting down
                     let _ = app_clone.emit(
@@ -348,6 +1053,7 @@ pub async fn spawn_process_with_logs(
                     );
                 }
             }
+            on_exit(exit_status);
         })
         .expect("Failed to spawn process wait thread");
 
@@ -358,6 +1064,129 @@ pub async fn spawn_process_with_logs(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_line_buffer_splits_on_newlines_and_carriage_returns() {
+        let mut buffer = LineBuffer::default();
+        let mut push = |chunk: &[u8]| -> Vec<String> {
+            buffer
+                .push(chunk, SystemTime::now())
+                .into_iter()
+                .map(|(_, line)| line)
+                .collect()
+        };
+
+        assert_eq!(push(b"first\nsecond\r"), vec!["first", "second"]);
+        // The `\n` completing a `\r\n` split across chunks isn't an extra line
+        assert_eq!(
+            push(b"\n\r 10%\r 50%\r100%\n"),
+            vec![" 10%", " 50%", "100%"]
+        );
+        assert_eq!(push(b"? Select a framework: "), Vec::<String>::new());
+        assert_eq!(
+            buffer.flush().map(|(_, line)| line).as_deref(),
+            Some("? Select a framework: ")
+        );
+        assert_eq!(buffer.flush(), None);
+    }
+
+    #[test]
+    fn test_line_buffer_stamps_lines_with_first_read() {
+        let mut buffer = LineBuffer::default();
+        let first_read = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        let second_read = SystemTime::UNIX_EPOCH + Duration::from_secs(20);
+
+        assert!(buffer.push(b"Proceed? ", first_read).is_empty());
+        assert_eq!(
+            buffer.push(b"(y/N)\ndone\n", second_read),
+            vec![
+                (first_read, "Proceed? (y/N)".to_string()),
+                (second_read, "done".to_string()),
+            ]
+        );
+
+        // A flushed partial line keeps its read time, not the flush time
+        buffer.push(b"50%", first_read);
+        assert_eq!(buffer.flush(), Some((first_read, "50%".to_string())));
+    }
+
+    #[test]
+    fn test_read_lines_flushes_unterminated_output() {
+        let mut lines = Vec::new();
+        read_lines(&b"building\r\nProceed? (y/N) "[..], |_, line| {
+            lines.push(line);
+            true
+        });
+
+        assert_eq!(lines, vec!["building", "Proceed? (y/N) "]);
+    }
+
+    #[test]
+    fn test_env_exports_reads_values_from_passthrough_vars() {
+        let env = HashMap::from([
+            ("NODE_ENV".to_string(), "development".to_string()),
+            ("API_URL".to_string(), "http://localhost/?q=it's\\&x=$HOME".to_string()),
+        ]);
+        assert_eq!(
+            env_exports("bash", &env),
+            "export API_URL=\"$RUNSTACK_ENV_API_URL\"; export NODE_ENV=\"$RUNSTACK_ENV_NODE_ENV\"; "
+        );
+        assert_eq!(
+            env_exports("fish", &env),
+            "set -gx API_URL $RUNSTACK_ENV_API_URL; set -gx NODE_ENV $RUNSTACK_ENV_NODE_ENV; "
+        );
+        assert_eq!(
+            env_exports("tcsh", &env),
+            "setenv API_URL $RUNSTACK_ENV_API_URL:q; setenv NODE_ENV $RUNSTACK_ENV_NODE_ENV:q; "
+        );
+        assert_eq!(env_exports("bash", &HashMap::new()), "");
+
+        let mut passthrough: Vec<_> = passthrough_env(&env).collect();
+        passthrough.sort();
+        assert_eq!(passthrough[0].0, "RUNSTACK_ENV_API_URL");
+        assert_eq!(passthrough[0].1, &env["API_URL"]);
+    }
+
+    #[test]
+    fn test_take_restarted_pid() {
+        let pid = u32::MAX - 7;
+        assert!(!take_restarted_pid(pid));
+
+        get_restarted_pids().lock().unwrap().insert(pid);
+        assert!(take_restarted_pid(pid));
+        // Only suppresses a single exit
+        assert!(!take_restarted_pid(pid));
+    }
+
+    #[test]
+    fn test_pause_and_resume_log_stream() {
+        let pid = u32::MAX - 8;
+        let mut emitted = 0;
+        assert!(emit_unless_paused(pid, || {
+            emitted += 1;
+            true
+        }));
+        assert_eq!(emitted, 1);
+
+        set_log_stream_enabled(pid, false);
+        // Paused lines aren't emitted, but the reader keeps going
+        assert!(emit_unless_paused(pid, || {
+            emitted += 1;
+            true
+        }));
+        assert_eq!(emitted, 1);
+        // Other processes keep streaming
+        assert!(is_log_stream_enabled(pid - 1));
+
+        set_log_stream_enabled(pid, true);
+        assert!(emit_unless_paused(pid, || {
+            emitted += 1;
+            true
+        }));
+        assert_eq!(emitted, 2);
+        // A failed emit stops the reader
+        assert!(!emit_unless_paused(pid, || false));
+    }
+
     #[test]
     fn test_escape_shell_single_quote_no_quotes() {
         // String without single quotes should remain unchanged
@@ -473,4 +1302,77 @@ mod tests {
         assert!(result.starts_with('\''), "Should start with quote");
         assert!(result.ends_with('\''), "Should end with quote");
     }
+
+    #[test]
+    fn test_get_shell_flags() {
+        assert_eq!(get_shell_flags("/bin/zsh"), vec!["-l", "-c"]);
+        assert_eq!(get_shell_flags("/usr/local/bin/fish"), vec!["-c"]);
+        assert_eq!(get_shell_flags("/bin/sh"), vec!["-c"]);
+    }
+
+    #[test]
+    fn test_get_shell_flags_windows_shells() {
+        assert_eq!(
+            get_shell_flags("C:\\Program Files\\PowerShell\\7\\pwsh.exe"),
+            vec!["-NoLogo", "-NonInteractive", "-Command"]
+        );
+        assert_eq!(get_shell_flags("cmd.exe"), vec!["/D", "/S", "/C"]);
+    }
+
+    #[test]
+    fn test_shell_script_quotes_for_each_shell() {
+        let args = vec!["run".to_string(), "dev's".to_string()];
+        let env = HashMap::from([("PORT".to_string(), "3001".to_string())]);
+
+        assert_eq!(
+            shell_script("/bin/zsh", "true", &env, "/projects/my app", "npm", &args).unwrap(),
+            "true; export PORT=\"$RUNSTACK_ENV_PORT\"; cd '/projects/my app' && 'npm' 'run' 'dev'\"'\"'s'"
+        );
+        assert_eq!(
+            shell_script("pwsh.exe", "", &env, "C:\\projects\\app", "npm", &args).unwrap(),
+            "& 'npm' 'run' 'dev''s'; exit $LASTEXITCODE"
+        );
+        assert_eq!(
+            shell_script("/opt/homebrew/bin/nu", "true", &env, "/projects/app", "npm", &args).unwrap(),
+            "'npm' 'run' 'dev'\"'\"'s'"
+        );
+        assert_eq!(
+            shell_script("cmd.exe", "", &env, "C:\\projects\\app", "npm", &args).unwrap(),
+            "\"npm\" \"run\" \"dev's\""
+        );
+        for unsafe_arg in ["%PATH%", "!PATH!", "a^b", "say \"hi\""] {
+            let args = vec![unsafe_arg.to_string()];
+            assert!(matches!(
+                shell_script("cmd.exe", "", &env, "C:\\projects\\app", "npm", &args),
+                Err(crate::error::AppError::CommandError(_))
+            ));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_parse_env_output() {
+        let env = parse_env_output(b"PATH=/usr/bin:/bin\0NVM_DIR=/home/me/.nvm\0EMPTY=\0MULTI=a\nb\0");
+        assert_eq!(env.get("PATH").map(String::as_str), Some("/usr/bin:/bin"));
+        assert_eq!(env.get("NVM_DIR").map(String::as_str), Some("/home/me/.nvm"));
+        assert_eq!(env.get("EMPTY").map(String::as_str), Some(""));
+        assert_eq!(env.get("MULTI").map(String::as_str), Some("a\nb"));
+    }
+
+    #[test]
+    fn test_shell_env_cache_populated_and_reused() {
+        // A local cache, so the session-wide SHELL_ENV_CACHE isn't touched
+        let cache = OnceLock::new();
+        let env = get_or_load_shell_env(&cache, || {
+            Ok(HashMap::from([("PATH".to_string(), "/prewarmed/bin".to_string())]))
+        })
+        .unwrap();
+        assert_eq!(env.get("PATH").map(String::as_str), Some("/prewarmed/bin"));
+
+        // The second call must not load the environment again
+        let reused =
+            get_or_load_shell_env(&cache, || panic!("shell environment loaded twice")).unwrap();
+        assert_eq!(reused.get("PATH").map(String::as_str), Some("/prewarmed/bin"));
+        assert!(std::ptr::eq(env, reused));
+    }
 }
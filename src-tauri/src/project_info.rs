@@ -1,9 +1,11 @@
-use crate::types::Project;
+use crate::error::AppError;
+use crate::types::{BudgetEntry, DevcontainerInfo, DiskInfo, PlatformConstraints, Project};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command as StdCommand;
 use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime};
 
 // Cache runtime versions to avoid repeated command executions
 static RUNTIME_VERSION_CACHE: OnceLock<std::sync::Mutex<HashMap<String, Option<String>>>> = OnceLock::new();
@@ -76,6 +78,17 @@ fn execute_version_command(runtime: &str) -> Option<String> {
                 .and_then(|output| String::from_utf8(output.stdout).ok())
                 .map(|v| v.trim().to_string())
         }
+        "Rust" => {
+            // `rustc --version` prints e.g. "rustc 1.79.0 (129f3b996 2024-06-10)"
+            StdCommand::new("rustc")
+                .arg("--version")
+                .output()
+                .ok()
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+                .and_then(|version_str| {
+                    version_str.split_whitespace().nth(1).map(|v| v.to_string())
+                })
+        }
         _ => None,
     }
 }
@@ -102,67 +115,3918 @@ pub fn get_package_json_scripts(path: &PathBuf) -> Option<HashMap<String, String
     None
 }
 
-pub fn get_directory_size(path: &PathBuf) -> Option<u64> {
-    let mut total_size = 0u64;
-    
-    // Directories to skip (common large directories that don't need to be counted)
-    let skip_dirs: &[&str] = &["node_modules", ".git", "dist", "build", ".next", ".turbo", ".cache"];
-    
-    fn calculate_size(path: &PathBuf, total: &mut u64, skip_dirs: &[&str]) {
-        if let Ok(entries) = fs::read_dir(path) {
-            for entry in entries.flatten() {
-                let entry_path = entry.path();
-                
-                // Use metadata() which is faster than separate is_dir() + metadata() calls
-                if let Ok(metadata) = entry_path.metadata() {
-                    if metadata.is_dir() {
-                        // Skip large directories to speed up calculation
-                        if let Some(name) = entry_path.file_name().and_then(|n| n.to_str()) {
-                            if skip_dirs.contains(&name) {
-                                continue;
-                            }
-                        }
-                        calculate_size(&entry_path, total, skip_dirs);
-                    } else {
-                        *total += metadata.len();
-                    }
+/// Directories to skip when walking a project tree
+/// (common large directories that don't need to be counted or watched)
+pub const SKIP_DIRS: &[&str] = &["node_modules", ".git", "dist", "build", ".next", ".turbo", ".cache"];
+
+/// Reads and parses package.json, returning None if it's missing or invalid
+pub fn read_package_json(path: &Path) -> Option<serde_json::Value> {
+    let content = fs::read_to_string(path.join("package.json")).ok()?;
+    serde_json::from_str::<serde_json::Value>(&content).ok()
+}
+
+/// Reads the crate name from the `[package]` table of Cargo.toml
+///
+/// Returns None when there's no Cargo.toml or it has no `[package]` name
+/// (e.g. a virtual workspace manifest).
+pub fn get_cargo_package_name(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path.join("Cargo.toml")).ok()?;
+    let mut in_package = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+            continue;
+        }
+        if !in_package {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if key.trim() == "name" {
+            let name = value.trim().trim_matches(|c| c == '"' || c == '\'');
+            return Some(name.to_string()).filter(|name| !name.is_empty());
+        }
+    }
+
+    None
+}
+
+/// Reads the package.json `os`/`cpu` arrays
+///
+/// Returns None when neither field restricts the supported platforms.
+pub fn get_platform_constraints(path: &Path) -> Option<PlatformConstraints> {
+    let json_value = read_package_json(path)?;
+
+    let read_list = |key: &str| -> Vec<String> {
+        json_value
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let constraints = PlatformConstraints {
+        os: read_list("os"),
+        cpu: read_list("cpu"),
+    };
+
+    if constraints.os.is_empty() && constraints.cpu.is_empty() {
+        None
+    } else {
+        Some(constraints)
+    }
+}
+
+/// Reads the command names a package installs from package.json's `bin` field
+///
+/// The string form installs one command named after the package (without
+/// its scope); the object form maps each command name to a script.
+pub fn get_bin_names(path: &Path) -> Vec<String> {
+    let Some(json_value) = read_package_json(path) else {
+        return Vec::new();
+    };
+
+    match json_value.get("bin") {
+        Some(serde_json::Value::String(_)) => json_value
+            .get("name")
+            .and_then(|name| name.as_str())
+            .and_then(|name| name.rsplit('/').next())
+            .filter(|name| !name.is_empty())
+            .map(|name| vec![name.to_string()])
+            .unwrap_or_default(),
+        Some(serde_json::Value::Object(commands)) => commands.keys().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Reads the tool versions pinned in package.json's `volta` field
+/// (e.g. `{ "node": "20.11.0", "npm": "10.2.4" }`)
+pub fn get_volta_pins(path: &Path) -> HashMap<String, String> {
+    read_package_json(path)
+        .and_then(|json_value| json_value.get("volta").and_then(|v| v.as_object()).cloned())
+        .map(|volta| {
+            volta
+                .iter()
+                .filter_map(|(tool, version)| {
+                    version.as_str().map(|v| (tool.clone(), v.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Reads the version of `tool` pinned by the package.json `packageManager` field
+fn get_package_manager_pin(path: &Path, tool: &str) -> Option<String> {
+    read_package_json(path)?
+        .get("packageManager")
+        .and_then(|v| v.as_str())
+        .and_then(|spec| spec.split_once('@'))
+        .filter(|(name, _)| *name == tool)
+        // Drop the integrity hash suffix (`bun@1.1.0+sha512.abc`)
+        .map(|(_, version)| version.split('+').next().unwrap_or(version).to_string())
+}
+
+/// Detects the runtime version pinned by the project
+///
+/// Pin sources are checked in this order, first match wins:
+/// 1. package.json `packageManager` field (e.g. `bun@1.1.0` pins the Bun runtime)
+/// 2. package.json `volta` field
+/// 3. `.tool-versions` (asdf/mise)
+/// 4. `.nvmrc` / `.node-version` (Node.js only)
+pub fn get_pinned_runtime_version(runtime: &str, path: &Path) -> Option<String> {
+    let tool = match runtime {
+        "Node.js" => "node",
+        "Bun" => "bun",
+        "Deno" => "deno",
+        _ => return None,
+    };
+
+    let from_package_manager = get_package_manager_pin(path, tool);
+    if from_package_manager.is_some() {
+        return from_package_manager;
+    }
+
+    if let Some(version) = get_volta_pins(path).remove(tool) {
+        return Some(version);
+    }
+
+    // asdf names the Node.js plugin "nodejs"; mise accepts both
+    let tool_versions_names: &[&str] = if tool == "node" {
+        &["nodejs", "node"]
+    } else {
+        &[tool]
+    };
+    let from_tool_versions = fs::read_to_string(path.join(".tool-versions"))
+        .ok()
+        .and_then(|content| {
+            content.lines().find_map(|line| {
+                let mut parts = line.split_whitespace();
+                let name = parts.next()?;
+                let version = parts.next()?;
+                tool_versions_names
+                    .contains(&name)
+                    .then(|| version.to_string())
+            })
+        });
+    if from_tool_versions.is_some() {
+        return from_tool_versions;
+    }
+
+    if tool == "node" {
+        return [".nvmrc", ".node-version"].iter().find_map(|file| {
+            fs::read_to_string(path.join(file))
+                .ok()
+                .map(|content| content.trim().to_string())
+                .filter(|version| !version.is_empty())
+        });
+    }
+
+    None
+}
+
+/// Detects the version of the project's package manager
+///
+/// Uses the version pinned in package.json `packageManager` when there is one.
+/// Otherwise yarn's generation is inferred from its config files: `.yarnrc.yml`
+/// or a yarn.lock with `__metadata` means Berry (reported as "2+"), anything
+/// else is classic ("1").
+pub fn get_package_manager_version(package_manager: &str, path: &Path) -> Option<String> {
+    if let Some(version) = get_package_manager_pin(path, package_manager) {
+        return Some(version);
+    }
+    if package_manager != "yarn" {
+        return None;
+    }
+
+    let berry_lockfile = fs::read_to_string(path.join("yarn.lock"))
+        .is_ok_and(|content| content.contains("__metadata:"));
+    if path.join(".yarnrc.yml").is_file() || berry_lockfile {
+        Some("2+".to_string())
+    } else {
+        Some("1".to_string())
+    }
+}
+
+/// Checks whether the package manager version is no longer actively developed
+///
+/// Only yarn classic (v1, maintenance mode) is flagged; returns None for
+/// package managers that aren't assessed.
+pub fn is_package_manager_deprecated(package_manager: &str, version: Option<&str>) -> Option<bool> {
+    if package_manager != "yarn" {
+        return None;
+    }
+
+    let major = version?.split('.').next()?;
+    Some(major == "1")
+}
+
+/// Version manager pin files, most specific first
+///
+/// Volta's package.json field is checked before these since it's tied to
+/// the project manifest itself.
+const VERSION_MANAGER_MARKERS: &[(&str, &str)] = &[
+    (".mise.toml", "mise"),
+    ("mise.toml", "mise"),
+    (".tool-versions", "asdf"),
+    (".nvmrc", "nvm"),
+    (".node-version", "fnm"),
+];
+
+/// Detects which Node.js version manager the project expects from its pin files
+pub fn get_version_manager(path: &Path) -> Option<String> {
+    if !get_volta_pins(path).is_empty() {
+        return Some("volta".to_string());
+    }
+
+    VERSION_MANAGER_MARKERS
+        .iter()
+        .find(|(file, _)| path.join(file).is_file())
+        .map(|(_, manager)| manager.to_string())
+}
+
+/// Node flags that take their value as the following argument
+const NODE_FLAGS_WITH_VALUE: &[&str] = &["--import", "--loader", "--experimental-loader"];
+
+/// Extracts the `--import`/`--loader`/`--experimental-*` flags passed to `node` in a script
+fn extract_node_flags(script: &str) -> Vec<String> {
+    let mut flags = Vec::new();
+    let mut words = script.split_whitespace();
+
+    while let Some(word) = words.next() {
+        if word != "node" && !word.ends_with("/node") {
+            continue;
+        }
+
+        // Node's own flags come before the entry file
+        while let Some(flag) = words.next() {
+            if !flag.starts_with('-') {
+                break;
+            }
+
+            let name = flag.split('=').next().unwrap_or(flag);
+            let is_loader_flag =
+                name == "--import" || name == "--loader" || name.starts_with("--experimental-");
+            if !is_loader_flag {
+                continue;
+            }
+
+            if NODE_FLAGS_WITH_VALUE.contains(&flag) {
+                match words.next() {
+                    Some(value) => flags.push(format!("{} {}", flag, value)),
+                    None => flags.push(flag.to_string()),
                 }
+            } else {
+                flags.push(flag.to_string());
             }
         }
     }
-    
-    calculate_size(path, &mut total_size, skip_dirs);
-    Some(total_size)
+
+    flags
 }
 
-pub fn get_modified_time(path: &PathBuf) -> Option<i64> {
-    if let Ok(metadata) = fs::metadata(path) {
-        if let Ok(modified) = metadata.modified() {
-            if let Ok(duration) = modified.duration_since(std::time::UNIX_EPOCH) {
-                return Some(duration.as_secs() as i64);
+/// Collects the loader-related node flags used by the dev and start scripts
+pub fn get_node_flags(scripts: &HashMap<String, String>) -> Vec<String> {
+    let mut flags: Vec<String> = Vec::new();
+
+    for script in ["dev", "start"]
+        .iter()
+        .filter_map(|name| scripts.get(*name))
+    {
+        for flag in extract_node_flags(script) {
+            if !flags.contains(&flag) {
+                flags.push(flag);
+            }
+        }
+    }
+
+    flags
+}
+
+/// Extracts the largest `--max-old-space-size` (in MB) set in a script or env value
+///
+/// Accepts V8's underscore spelling and a space instead of `=`.
+fn extract_max_old_space_size(text: &str) -> Option<u32> {
+    let mut largest = None;
+
+    for flag in ["--max-old-space-size", "--max_old_space_size"] {
+        for (index, _) in text.match_indices(flag) {
+            let value: String = text[index + flag.len()..]
+                .trim_start_matches(['=', ' '])
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            if let Ok(megabytes) = value.parse::<u32>() {
+                largest = largest.max(Some(megabytes));
             }
         }
     }
+
+    largest
+}
+
+/// Reads the Node heap limit the project asks for via `--max-old-space-size`
+///
+/// Checks every package.json script and the `NODE_OPTIONS` entry of `.env`
+/// (no other `.env` values are read), returning the largest limit in MB.
+pub fn get_node_memory_limit_mb(path: &PathBuf) -> Option<u32> {
+    let from_scripts = get_package_json_scripts(path)
+        .unwrap_or_default()
+        .values()
+        .filter_map(|script| extract_max_old_space_size(script))
+        .max();
+
+    let from_env = fs::read_to_string(path.join(".env"))
+        .ok()
+        .and_then(|content| {
+            content
+                .lines()
+                .map(|line| line.trim())
+                .map(|line| line.strip_prefix("export ").unwrap_or(line))
+                .find(|line| line.starts_with("NODE_OPTIONS="))
+                .and_then(extract_max_old_space_size)
+        });
+
+    from_scripts.max(from_env)
+}
+
+/// Lists the package names in package.json `dependencies` and `devDependencies`
+fn get_dependency_names(path: &Path) -> Vec<String> {
+    let Some(package_json) = read_package_json(path) else {
+        return Vec::new();
+    };
+
+    ["dependencies", "devDependencies"]
+        .iter()
+        .filter_map(|field| package_json.get(field).and_then(|deps| deps.as_object()))
+        .flat_map(|deps| deps.keys().cloned())
+        .collect()
+}
+
+/// Checks whether package.json declares a dependency matching `pattern` (see `dependency_matches()`)
+pub fn has_dependency(path: &Path, pattern: &str) -> bool {
+    get_dependency_names(path)
+        .iter()
+        .any(|name| dependency_matches(pattern, name))
+}
+
+/// Hashes a package's `name` and dependency names into a cheap copy-detection fingerprint
+///
+/// Versions are ignored, so a copy that drifted slightly still matches.
+/// Returns None for packages without a name.
+pub fn project_fingerprint(path: &Path) -> Option<u64> {
+    use std::hash::{Hash, Hasher};
+
+    let package_json = read_package_json(path)?;
+    let name = package_json.get("name")?.as_str()?;
+    let dependencies: std::collections::BTreeSet<&String> = ["dependencies", "devDependencies"]
+        .iter()
+        .filter_map(|field| package_json.get(field).and_then(|deps| deps.as_object()))
+        .flat_map(|deps| deps.keys())
+        .collect();
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    name.hash(&mut hasher);
+    dependencies.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Checks a package name against a pattern; a pattern ending in `/` matches the whole scope
+fn dependency_matches(pattern: &str, name: &str) -> bool {
+    if pattern.ends_with('/') {
+        name.starts_with(pattern)
+    } else {
+        name == pattern
+    }
+}
+
+/// CSS frameworks in priority order: (name, config file prefix, dependency names)
+///
+/// Dependency patterns follow `dependency_matches()`.
+const CSS_FRAMEWORKS: &[(&str, Option<&str>, &[&str])] = &[
+    ("tailwind", Some("tailwind.config."), &["tailwindcss"]),
+    ("unocss", Some("uno.config."), &["unocss"]),
+    ("chakra", None, &["@chakra-ui/"]),
+    ("styled-components", None, &["styled-components"]),
+    ("bootstrap", None, &["bootstrap", "react-bootstrap"]),
+    ("sass", None, &["sass", "node-sass"]),
+];
+
+/// Detects the project's primary CSS framework from config files and dependencies
+///
+/// Config files (`tailwind.config.*`, `uno.config.*`) win over dependencies;
+/// among dependencies the first match in `CSS_FRAMEWORKS` order is reported.
+pub fn get_css_framework(path: &Path) -> Option<String> {
+    let file_names: Vec<String> = fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| entry.file_name().to_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let from_config = CSS_FRAMEWORKS.iter().find(|(_, config_prefix, _)| {
+        config_prefix.is_some_and(|prefix| file_names.iter().any(|name| name.starts_with(prefix)))
+    });
+    if let Some((name, _, _)) = from_config {
+        return Some(name.to_string());
+    }
+
+    let dependency_names = get_dependency_names(path);
+
+    CSS_FRAMEWORKS
+        .iter()
+        .find(|(_, _, patterns)| {
+            patterns.iter().any(|pattern| {
+                dependency_names
+                    .iter()
+                    .any(|name| dependency_matches(pattern, name))
+            })
+        })
+        .map(|(name, _, _)| name.to_string())
+}
+
+/// Env files a dev server loads, highest precedence first
+pub const DEV_ENV_FILES: &[&str] = &[
+    ".env.development.local",
+    ".env.local",
+    ".env.development",
+    ".env",
+];
+
+/// Reads `name` from the first of the project's env `files` that sets it
+///
+/// Accepts `export NAME=value`, and strips quotes and trailing ` #` comments.
+pub fn read_env_value(path: &Path, files: &[&str], name: &str) -> Option<String> {
+    files
+        .iter()
+        .filter_map(|file| fs::read_to_string(path.join(file)).ok())
+        .find_map(|content| {
+            content.lines().find_map(|line| {
+                let line = line.trim();
+                let line = line.strip_prefix("export ").unwrap_or(line);
+                let value = line.strip_prefix(name)?.trim_start().strip_prefix('=')?;
+                let value = value.split(" #").next().unwrap_or(value);
+                Some(value.trim().trim_matches(['"', '\'']).to_string())
+            })
+        })
+}
+
+/// Analytics and error-reporting SDKs: (provider, dependency patterns)
+///
+/// Dependency patterns follow `dependency_matches()`.
+const TELEMETRY_PROVIDERS: &[(&str, &[&str])] = &[
+    (
+        "vercel-analytics",
+        &["@vercel/analytics", "@vercel/speed-insights"],
+    ),
+    ("posthog", &["posthog-js", "posthog-node"]),
+    ("sentry", &["@sentry/"]),
+    ("google-analytics", &["react-ga4", "react-ga", "vue-gtag"]),
+    ("plausible", &["next-plausible", "plausible-tracker"]),
+    (
+        "segment",
+        &["@segment/analytics-next", "@segment/analytics-node"],
+    ),
+    ("mixpanel", &["mixpanel-browser", "mixpanel"]),
+];
+
+/// Checks whether a Next.js project opts out of Next's anonymous telemetry
+///
+/// Looks for `NEXT_TELEMETRY_DISABLED=1` in the dev env files or set inline
+/// by a package.json script. Returns None when the project doesn't use Next.js.
+pub fn get_next_telemetry_disabled(path: &Path) -> Option<bool> {
+    if !get_dependency_names(path).iter().any(|name| name == "next") {
+        return None;
+    }
+
+    let is_disabling_value = |value: &str| value == "1" || value == "true";
+    let from_env = read_env_value(path, DEV_ENV_FILES, "NEXT_TELEMETRY_DISABLED")
+        .is_some_and(|value| is_disabling_value(&value));
+    let from_scripts = get_package_json_scripts(&path.to_path_buf())
+        .unwrap_or_default()
+        .values()
+        .any(|script| {
+            script.split_whitespace().any(|word| {
+                word.strip_prefix("NEXT_TELEMETRY_DISABLED=")
+                    .is_some_and(is_disabling_value)
+            })
+        });
+
+    Some(from_env || from_scripts)
+}
+
+/// Dev script flags that turn on extra logging
+const VERBOSE_FLAGS: &[&str] = &["--debug", "--verbose"];
+
+/// Detects a setting that makes the dev server log more than usual
+///
+/// Looks for a `DEBUG=` assignment or `--debug`/`--verbose` in the dev script,
+/// then `DEBUG` in the dev env files. Returns the setting as written (e.g.
+/// `DEBUG=app:*` or `--verbose`), or None when the dev server runs normally.
+pub fn get_dev_verbosity(path: &Path) -> Option<String> {
+    let is_enabled = |value: &str| !matches!(value, "" | "0" | "false");
+
+    let from_script = get_package_json_scripts(&path.to_path_buf())
+        .and_then(|scripts| scripts.get("dev").cloned())
+        .and_then(|script| {
+            script
+                .split_whitespace()
+                .find(|word| {
+                    VERBOSE_FLAGS.contains(word)
+                        || word
+                            .strip_prefix("DEBUG=")
+                            .is_some_and(|value| is_enabled(value.trim_matches(['"', '\''])))
+                })
+                .map(|word| word.replace(['"', '\''], ""))
+        });
+    if from_script.is_some() {
+        return from_script;
+    }
+
+    read_env_value(path, DEV_ENV_FILES, "DEBUG")
+        .filter(|value| is_enabled(value))
+        .map(|value| format!("DEBUG={}", value))
+}
+
+/// Detects which Next.js router the project uses: "app", "pages" or "both"
+///
+/// Based on `app/` and `pages/` directories at the root or under `src/`.
+/// Returns None for other frameworks or when neither directory exists.
+pub fn get_router_type(path: &Path, framework: &str) -> Option<String> {
+    if framework != "nextjs" {
+        return None;
+    }
+
+    let has_dir = |name: &str| path.join(name).is_dir() || path.join("src").join(name).is_dir();
+    match (has_dir("app"), has_dir("pages")) {
+        (true, true) => Some("both".to_string()),
+        (true, false) => Some("app".to_string()),
+        (false, true) => Some("pages".to_string()),
+        (false, false) => None,
+    }
+}
+
+/// Lists the analytics/telemetry providers the project sends data to
+///
+/// Providers come from dependencies in `TELEMETRY_PROVIDERS` order; a Next.js
+/// project that hasn't disabled Next's own telemetry adds "next-telemetry".
+pub fn get_telemetry(path: &Path) -> Vec<String> {
+    let dependency_names = get_dependency_names(path);
+
+    let mut providers: Vec<String> = TELEMETRY_PROVIDERS
+        .iter()
+        .filter(|(_, patterns)| {
+            patterns.iter().any(|pattern| {
+                dependency_names
+                    .iter()
+                    .any(|name| dependency_matches(pattern, name))
+            })
+        })
+        .map(|(provider, _)| provider.to_string())
+        .collect();
+
+    if get_next_telemetry_disabled(path) == Some(false) {
+        providers.push("next-telemetry".to_string());
+    }
+
+    providers
+}
+
+/// State management and data-fetching libraries: (tag, dependency patterns)
+///
+/// Dependency patterns follow `dependency_matches()`.
+const DATA_LIBRARIES: &[(&str, &[&str])] = &[
+    ("react-query", &["@tanstack/react-query", "react-query"]),
+    ("swr", &["swr"]),
+    ("trpc", &["@trpc/"]),
+    ("apollo", &["@apollo/client", "apollo-client"]),
+    ("redux", &["redux", "@reduxjs/toolkit"]),
+    ("zustand", &["zustand"]),
+    ("jotai", &["jotai"]),
+    ("mobx", &["mobx"]),
+    ("pinia", &["pinia"]),
+];
+
+/// Lists the state management and data-fetching libraries the project depends on
+///
+/// Tags are returned in `DATA_LIBRARIES` order, each at most once.
+pub fn get_data_libs(path: &Path) -> Vec<String> {
+    let dependency_names = get_dependency_names(path);
+
+    DATA_LIBRARIES
+        .iter()
+        .filter(|(_, patterns)| {
+            patterns.iter().any(|pattern| {
+                dependency_names
+                    .iter()
+                    .any(|name| dependency_matches(pattern, name))
+            })
+        })
+        .map(|(tag, _)| tag.to_string())
+        .collect()
+}
+
+/// API frameworks in priority order: (name, dependency names)
+///
+/// NestJS runs on Express or Fastify, so it's checked before them.
+const API_FRAMEWORKS: &[(&str, &[&str])] = &[
+    ("nestjs", &["@nestjs/core"]),
+    ("hono", &["hono"]),
+    ("fastify", &["fastify"]),
+    ("koa", &["koa"]),
+    ("express", &["express"]),
+];
+
+/// Detects the backend framework serving the project's API from its dependencies
+///
+/// Reported separately from the web framework so backend-only projects aren't
+/// labeled as generic "node". The first match in `API_FRAMEWORKS` order wins.
+pub fn get_api_framework(path: &Path) -> Option<String> {
+    let dependency_names = get_dependency_names(path);
+
+    API_FRAMEWORKS
+        .iter()
+        .find(|(_, dependencies)| {
+            dependencies
+                .iter()
+                .any(|dependency| dependency_names.iter().any(|name| name == dependency))
+        })
+        .map(|(name, _)| name.to_string())
+}
+
+/// Auth libraries in priority order: (name, dependency patterns)
+///
+/// `@supabase/supabase-js` is often just the database client and Passport
+/// is generic middleware, so dedicated auth libraries are checked first.
+const AUTH_LIBS: &[(&str, &[&str])] = &[
+    ("next-auth", &["next-auth", "@auth/"]),
+    ("clerk", &["@clerk/"]),
+    ("lucia", &["lucia", "@lucia-auth/"]),
+    (
+        "supabase",
+        &[
+            "@supabase/ssr",
+            "@supabase/auth-helpers-nextjs",
+            "@supabase/supabase-js",
+        ],
+    ),
+    ("passport", &["passport"]),
+];
+
+/// Detects the project's primary auth library from its dependencies
+///
+/// The first match in `AUTH_LIBS` order wins. Patterns follow `dependency_matches()`.
+pub fn get_auth_lib(path: &Path) -> Option<String> {
+    let dependency_names = get_dependency_names(path);
+
+    AUTH_LIBS
+        .iter()
+        .find(|(_, patterns)| {
+            patterns.iter().any(|pattern| {
+                dependency_names
+                    .iter()
+                    .any(|name| dependency_matches(pattern, name))
+            })
+        })
+        .map(|(name, _)| name.to_string())
+}
+
+/// Headless CMS SDKs, in priority order: (content source name, dependency patterns)
+const CMS_DEPENDENCIES: &[(&str, &[&str])] = &[
+    ("contentful", &["contentful"]),
+    ("sanity", &["@sanity/client", "next-sanity"]),
+    ("strapi", &["@strapi/"]),
+];
+
+/// Directories checked for `.mdx` files
+const MDX_DIRS: &[&str] = &["src", "content", "docs", "pages"];
+
+/// Maximum number of files inspected per directory when looking for `.mdx` files
+const MAX_MDX_SAMPLE_FILES: usize = 500;
+
+/// Detects where the project's content comes from at build time
+///
+/// Headless CMS SDKs (`contentful`, `sanity`, `strapi`) win, then Astro content
+/// collections in `src/content/` (`astro-content`), then MDX via an `@mdx-js/*`
+/// dependency or `.mdx` files (`mdx`).
+pub fn get_content_source(path: &PathBuf) -> Option<String> {
+    let dependency_names = get_dependency_names(path);
+    let has_dependency = |pattern: &str| {
+        dependency_names
+            .iter()
+            .any(|name| dependency_matches(pattern, name))
+    };
+
+    let cms = CMS_DEPENDENCIES
+        .iter()
+        .find(|(_, patterns)| patterns.iter().any(|pattern| has_dependency(pattern)));
+    if let Some((name, _)) = cms {
+        return Some(name.to_string());
+    }
+
+    if path.join("src").join("content").is_dir()
+        && crate::detection::detect_framework_from_path(path) == "astro"
+    {
+        return Some("astro-content".to_string());
+    }
+
+    let has_mdx_files = || {
+        MDX_DIRS.iter().any(|dir| {
+            sample_files(&path.join(dir), MAX_MDX_SAMPLE_FILES)
+                .iter()
+                .any(|file| file.extension().is_some_and(|e| e == "mdx"))
+        })
+    };
+    if has_dependency("@mdx-js/") || has_mdx_files() {
+        return Some("mdx".to_string());
+    }
+
     None
 }
 
-pub fn enrich_project_info(mut project: Project) -> Project {
-    let path = PathBuf::from(&project.path);
-    
-    // Get runtime version
-    project.runtime_version = get_runtime_version(&project.runtime, &path);
-    
-    // Get scripts from package.json (only for Node.js/Bun projects)
-    if project.runtime == "Node.js" || project.runtime == "Bun" {
-        project.scripts = get_package_json_scripts(&path);
+/// README file names checked for documented commands, in order
+const README_FILES: &[&str] = &[
+    "README.md",
+    "readme.md",
+    "Readme.md",
+    "README.markdown",
+    "README",
+];
+
+/// Maximum number of README bytes read when extracting commands
+const MAX_README_BYTES: u64 = 256 * 1024;
+
+/// Programs a documented command has to start with to count as runnable
+const RUN_COMMAND_PROGRAMS: &[&str] = &[
+    "npm", "npx", "pnpm", "pnpx", "yarn", "bun", "bunx", "node", "deno", "tsx", "ts-node",
+];
+
+/// Fenced code block languages that hold shell commands (empty means unlabeled)
+const SHELL_FENCE_LANGUAGES: &[&str] = &["", "sh", "bash", "zsh", "shell", "console", "terminal"];
+
+/// Extracts run commands from the shell code blocks of a README
+fn extract_documented_commands(readme: &str) -> Vec<String> {
+    let mut commands: Vec<String> = Vec::new();
+    let mut in_shell_block = false;
+    let mut in_other_block = false;
+
+    for line in readme.lines() {
+        let trimmed = line.trim();
+
+        if let Some(language) = trimmed.strip_prefix("```") {
+            if in_shell_block || in_other_block {
+                in_shell_block = false;
+                in_other_block = false;
+            } else if SHELL_FENCE_LANGUAGES.contains(&language.trim().to_lowercase().as_str()) {
+                in_shell_block = true;
+            } else {
+                in_other_block = true;
+            }
+            continue;
+        }
+
+        if !in_shell_block {
+            continue;
+        }
+
+        // Drop prompt markers like `$ npm run dev`
+        let command = trimmed.trim_start_matches(['$', '>']).trim();
+        let program = command.split_whitespace().next().unwrap_or("");
+        if RUN_COMMAND_PROGRAMS.contains(&program) && !commands.iter().any(|c| c == command) {
+            commands.push(command.to_string());
+        }
     }
-    
-    // Get directory size
-    project.size = get_directory_size(&path);
-    
-    // Get modified time
-    project.modified = get_modified_time(&path);
-    
-    project
+
+    commands
+}
+
+/// Returns the run commands documented in the project's README
+///
+/// Only fenced shell code blocks are considered, and only lines starting with
+/// a known runtime or package manager (`npm`, `node`, `bun`, ...). The README
+/// is read up to a size cap so huge generated READMEs don't stall a scan.
+pub fn documented_commands(path: &Path) -> Vec<String> {
+    use std::io::Read;
+
+    let Some(file) = README_FILES
+        .iter()
+        .find_map(|name| fs::File::open(path.join(name)).ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut bytes = Vec::new();
+    if file.take(MAX_README_BYTES).read_to_end(&mut bytes).is_err() {
+        return Vec::new();
+    }
+
+    extract_documented_commands(&String::from_utf8_lossy(&bytes))
+}
+
+/// Strips comments and trailing commas from JSONC so serde_json can parse it
+///
+/// Handles `//` and `/* */` comments outside string literals, and commas
+/// directly before a closing `}` or `]`, which is what tsconfig,
+/// devcontainer.json and deno.jsonc files use in practice.
+pub fn strip_jsonc(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    output.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        output.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            _ => output.push(c),
+        }
+    }
+
+    // Second pass: drop commas directly before a closing bracket, outside strings
+    let mut result = String::with_capacity(output.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let chars: Vec<char> = output.chars().collect();
+    for (index, &c) in chars.iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let next = chars[index + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        result.push(c);
+    }
+
+    result
+}
+
+/// Locations VS Code looks for a devcontainer definition, in order
+const DEVCONTAINER_FILES: &[&str] = &[".devcontainer/devcontainer.json", ".devcontainer.json"];
+
+/// Checks whether the project defines a VS Code devcontainer
+pub fn has_devcontainer(path: &Path) -> bool {
+    DEVCONTAINER_FILES
+        .iter()
+        .any(|file| path.join(file).is_file())
+}
+
+/// Config files that mark lefthook as the project's hook manager
+const LEFTHOOK_FILES: &[&str] = &[
+    "lefthook.yml",
+    "lefthook.yaml",
+    ".lefthook.yml",
+    ".lefthook.yaml",
+];
+
+/// Detects the git hook manager enforcing hooks: "husky", "lefthook" or "simple-git-hooks"
+pub fn get_git_hooks(path: &Path) -> Option<String> {
+    if path.join(".husky").is_dir() {
+        return Some("husky".to_string());
+    }
+    if LEFTHOOK_FILES.iter().any(|file| path.join(file).is_file()) {
+        return Some("lefthook".to_string());
+    }
+
+    read_package_json(path)
+        .is_some_and(|json| json.get("simple-git-hooks").is_some())
+        .then(|| "simple-git-hooks".to_string())
 }
 
+/// Reads the name and forwarded ports from the project's devcontainer.json
+pub fn devcontainer_info(path: &Path) -> Result<DevcontainerInfo, AppError> {
+    let content = DEVCONTAINER_FILES
+        .iter()
+        .find_map(|file| fs::read_to_string(path.join(file)).ok())
+        .ok_or_else(|| {
+            AppError::NotFound(format!("No devcontainer.json found in {}", path.display()))
+        })?;
+
+    let config: serde_json::Value = serde_json::from_str(&strip_jsonc(&content))
+        .map_err(|e| AppError::ParseError(format!("Invalid devcontainer.json: {}", e)))?;
+
+    let forward_ports = config
+        .get("forwardPorts")
+        .and_then(|ports| ports.as_array())
+        .map(|ports| {
+            ports
+                .iter()
+                .filter_map(|port| match port {
+                    serde_json::Value::Number(number) => Some(number.to_string()),
+                    serde_json::Value::String(host_port) => Some(host_port.clone()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(DevcontainerInfo {
+        name: config
+            .get("name")
+            .and_then(|name| name.as_str())
+            .map(String::from),
+        forward_ports,
+    })
+}
+
+/// Reads the `budgets` arrays from every project's build target in angular.json
+fn parse_angular_budgets(config: &serde_json::Value) -> Vec<BudgetEntry> {
+    let mut entries: Vec<BudgetEntry> = Vec::new();
+    let Some(projects) = config.get("projects").and_then(|p| p.as_object()) else {
+        return entries;
+    };
+
+    for project in projects.values() {
+        let Some(build) = project.pointer("/architect/build") else {
+            continue;
+        };
+
+        // Budgets usually live in the production configuration, but may be set for all builds
+        let configurations = build
+            .get("configurations")
+            .and_then(|c| c.as_object())
+            .into_iter()
+            .flat_map(|configurations| configurations.values());
+        let budget_lists = build
+            .get("options")
+            .into_iter()
+            .chain(configurations)
+            .filter_map(|options| options.get("budgets").and_then(|b| b.as_array()));
+
+        for budget in budget_lists.flatten() {
+            let read = |key: &str| budget.get(key).and_then(|v| v.as_str()).map(String::from);
+            let Some(budget_type) = read("type") else {
+                continue;
+            };
+            let entry = BudgetEntry {
+                source: "angular".to_string(),
+                target: match read("name") {
+                    Some(name) => format!("{}:{}", budget_type, name),
+                    None => budget_type,
+                },
+                max_warning: read("maximumWarning"),
+                max_error: read("maximumError"),
+            };
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+    }
+
+    entries
+}
+
+/// Reads the package.json `bundlesize` array (`[{ "path": ..., "maxSize": ... }]`)
+fn parse_bundlesize(package_json: &serde_json::Value) -> Vec<BudgetEntry> {
+    package_json
+        .get("bundlesize")
+        .and_then(|b| b.as_array())
+        .map(|files| {
+            files
+                .iter()
+                .filter_map(|file| {
+                    Some(BudgetEntry {
+                        source: "bundlesize".to_string(),
+                        target: file.get("path")?.as_str()?.to_string(),
+                        max_warning: None,
+                        max_error: file
+                            .get("maxSize")
+                            .and_then(|size| size.as_str())
+                            .map(String::from),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Collects the bundle-size budgets from angular.json and package.json `bundlesize`
+///
+/// Returns None when the project doesn't configure any budgets.
+pub fn detect_size_budget(path: &Path) -> Result<Option<Vec<BudgetEntry>>, AppError> {
+    let mut entries = Vec::new();
+
+    if let Ok(content) = fs::read_to_string(path.join("angular.json")) {
+        let config: serde_json::Value = serde_json::from_str(&strip_jsonc(&content))
+            .map_err(|e| AppError::ParseError(format!("Invalid angular.json: {}", e)))?;
+        entries.extend(parse_angular_budgets(&config));
+    }
+
+    if let Some(package_json) = read_package_json(path) {
+        entries.extend(parse_bundlesize(&package_json));
+    }
+
+    Ok(Some(entries).filter(|entries| !entries.is_empty()))
+}
+
+/// Joins a tsconfig path target onto `baseUrl`, dropping `./` segments
+fn resolve_alias_target(base_url: &str, target: &str) -> String {
+    base_url
+        .split('/')
+        .chain(target.split('/'))
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Reads import aliases from tsconfig.json (or jsconfig.json) `compilerOptions.paths`
+///
+/// Maps each alias pattern to its first target, relative to the project root
+/// (e.g. `@/*` → `src/*`). Returns an empty map when no aliases are configured.
+pub fn detect_import_aliases(path: &Path) -> Result<HashMap<String, String>, AppError> {
+    let Some((file, content)) = ["tsconfig.json", "jsconfig.json"]
+        .iter()
+        .find_map(|file| Some((file, fs::read_to_string(path.join(file)).ok()?)))
+    else {
+        return Ok(HashMap::new());
+    };
+
+    let config: serde_json::Value = serde_json::from_str(&strip_jsonc(&content))
+        .map_err(|e| AppError::ParseError(format!("Invalid {}: {}", file, e)))?;
+    let Some(compiler_options) = config.get("compilerOptions") else {
+        return Ok(HashMap::new());
+    };
+
+    // Targets are resolved against baseUrl when it's set, otherwise the config's directory
+    let base_url = compiler_options
+        .get("baseUrl")
+        .and_then(|b| b.as_str())
+        .unwrap_or(".");
+
+    let aliases = compiler_options
+        .get("paths")
+        .and_then(|p| p.as_object())
+        .map(|paths| {
+            paths
+                .iter()
+                .filter_map(|(alias, targets)| {
+                    let target = targets.as_array()?.first()?.as_str()?;
+                    Some((alias.clone(), resolve_alias_target(base_url, target)))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(aliases)
+}
+
+/// Template env files that document the variables a project expects
+const ENV_TEMPLATE_FILES: &[&str] = &[".env.example", ".env.sample"];
+
+/// Lists the variable names declared in the project's `.env.example`/`.env.sample`
+///
+/// Only names are read; example values are ignored. Names are returned in file
+/// order without duplicates, and the list is empty when there's no template.
+pub fn required_env_vars(path: &Path) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+
+    for content in ENV_TEMPLATE_FILES
+        .iter()
+        .filter_map(|file| fs::read_to_string(path.join(file)).ok())
+    {
+        for line in content.lines() {
+            let line = line.trim();
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            if line.starts_with('#') {
+                continue;
+            }
+
+            let Some((name, _)) = line.split_once('=') else {
+                continue;
+            };
+            let name = name.trim();
+            let is_valid_name = !name.is_empty()
+                && !name.starts_with(|c: char| c.is_ascii_digit())
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if is_valid_name && !names.iter().any(|existing| existing == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    names
+}
+
+/// Directories a framework serves static assets from, in the order they're checked
+fn public_dir_candidates(framework: &str) -> &'static [&'static str] {
+    match framework {
+        "sveltekit" => &["static"],
+        // Nuxt 3 uses public/, Nuxt 2 used static/
+        "nuxt" => &["public", "static"],
+        // Gatsby builds into public/ and serves assets from static/
+        "gatsby" => &["static"],
+        _ => &["public"],
+    }
+}
+
+/// Returns the project's static asset directory, if it exists
+pub fn detect_public_dir(path: &PathBuf) -> Option<String> {
+    let framework = crate::detection::detect_framework_from_path(path);
+
+    public_dir_candidates(framework)
+        .iter()
+        .map(|dir| path.join(dir))
+        .find(|dir| dir.is_dir())
+        .map(|dir| dir.to_string_lossy().to_string())
+}
+
+/// Files a plain Node server usually starts from, when package.json has no `main`
+const ENTRY_POINT_CANDIDATES: &[&str] = &[
+    "server.js",
+    "server.mjs",
+    "server.ts",
+    "index.js",
+    "index.mjs",
+    "index.ts",
+    "app.js",
+    "app.ts",
+    "src/server.js",
+    "src/server.ts",
+    "src/index.js",
+    "src/index.ts",
+    "src/app.js",
+    "src/app.ts",
+];
+
+/// Finds the file a Node project starts from: package.json `main`, then a common entry file
+pub fn detect_entry_point(path: &Path) -> Option<PathBuf> {
+    read_package_json(path)
+        .and_then(|json| json.get("main")?.as_str().map(|main| path.join(main)))
+        .filter(|main| main.is_file())
+        .or_else(|| {
+            ENTRY_POINT_CANDIDATES
+                .iter()
+                .map(|file| path.join(file))
+                .find(|file| file.is_file())
+        })
+}
+
+/// Where bundle analyzers write their HTML reports, most specific first
+const BUNDLE_REPORTS: &[&str] = &[
+    // @next/bundle-analyzer
+    ".next/analyze/client.html",
+    ".next/analyze/nodejs.html",
+    ".next/analyze/edge.html",
+    // rollup-plugin-visualizer
+    "stats.html",
+    "dist/stats.html",
+    // webpack-bundle-analyzer in static mode
+    "dist/report.html",
+    "build/report.html",
+];
+
+/// Returns the relative path of a generated bundle-analysis report, if one exists
+pub fn find_bundle_report(path: &Path) -> Option<String> {
+    BUNDLE_REPORTS
+        .iter()
+        .find(|report| path.join(report).is_file())
+        .map(|report| report.to_string())
+}
+
+/// Docker Compose files, in the order Compose itself looks for them
+pub const COMPOSE_FILES: &[&str] = &[
+    "compose.yaml",
+    "compose.yml",
+    "docker-compose.yaml",
+    "docker-compose.yml",
+];
+
+/// Compose service names that are almost always a database
+const DATABASE_SERVICES: &[&str] = &[
+    "db",
+    "database",
+    "postgres",
+    "postgresql",
+    "mysql",
+    "mariadb",
+    "mongo",
+    "mongodb",
+];
+
+/// Database client packages
+const DATABASE_DEPENDENCIES: &[&str] = &[
+    "pg",
+    "postgres",
+    "mysql",
+    "mysql2",
+    "mongodb",
+    "mongoose",
+    "@prisma/client",
+];
+
+/// Commands in a `predev` script that start a local database
+const DATABASE_START_COMMANDS: &[&str] = &[
+    "docker compose up",
+    "docker-compose up",
+    "pg_ctl",
+    "brew services start",
+];
+
+/// Lists the service names under the top-level `services:` key of a compose file
+fn parse_compose_services(content: &str) -> Vec<String> {
+    let mut services = Vec::new();
+    let mut in_services = false;
+    let mut service_indent = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let indent = line.len() - trimmed.len();
+        if indent == 0 {
+            in_services = line.trim_end() == "services:";
+            continue;
+        }
+        if !in_services || indent != *service_indent.get_or_insert(indent) {
+            continue;
+        }
+
+        if let Some((name, _)) = trimmed.split_once(':') {
+            services.push(name.trim_matches(|c| c == '"' || c == '\'').to_string());
+        }
+    }
+
+    services
+}
+
+/// Guesses whether the dev server needs a local database running first
+///
+/// True when a compose file defines a database service, a `predev` script
+/// starts one, or a database client is installed alongside a migration
+/// command. Returns None when there's neither a package.json nor a compose
+/// file to judge from.
+pub fn requires_database(path: &Path) -> Option<bool> {
+    let compose = COMPOSE_FILES
+        .iter()
+        .find_map(|file| fs::read_to_string(path.join(file)).ok());
+    let has_package_json = path.join("package.json").is_file();
+    if compose.is_none() && !has_package_json {
+        return None;
+    }
+
+    let has_database_service = compose.is_some_and(|content| {
+        parse_compose_services(&content)
+            .iter()
+            .any(|service| DATABASE_SERVICES.contains(&service.to_lowercase().as_str()))
+    });
+    if has_database_service {
+        return Some(true);
+    }
+
+    let path = path.to_path_buf();
+    let predev_starts_database = get_package_json_scripts(&path)
+        .and_then(|scripts| scripts.get("predev").cloned())
+        .is_some_and(|predev| {
+            DATABASE_START_COMMANDS
+                .iter()
+                .any(|command| predev.contains(command))
+        });
+    if predev_starts_database {
+        return Some(true);
+    }
+
+    let has_database_client = get_dependency_names(&path)
+        .iter()
+        .any(|name| DATABASE_DEPENDENCIES.contains(&name.as_str()));
+    let has_migrations = crate::scripts::select_migration_command(&path).is_some();
+    Some(has_database_client && has_migrations)
+}
+
+/// Conventional directories to offer for quick navigation, in the order they're checked
+const KEY_DIRECTORY_CANDIDATES: &[(&str, &[&str])] = &[
+    ("src", &["src"]),
+    ("tests", &["tests", "__tests__", "test", "src/__tests__"]),
+    (
+        "components",
+        &["components", "src/components", "app/components"],
+    ),
+    // Next.js, Nuxt and Astro keep routes in pages/ or the app/ router
+    ("pages", &["pages", "src/pages", "app", "src/app"]),
+];
+
+/// Finds the project's conventional directories (source, tests, components, pages, public)
+///
+/// Maps each key to the first matching directory that exists; keys without
+/// a match are left out. The public directory follows `detect_public_dir`.
+pub fn key_directories(path: &Path) -> HashMap<String, String> {
+    let mut directories: HashMap<String, String> = KEY_DIRECTORY_CANDIDATES
+        .iter()
+        .filter_map(|(key, candidates)| {
+            let dir = candidates
+                .iter()
+                .map(|dir| path.join(dir))
+                .find(|dir| dir.is_dir())?;
+            Some((key.to_string(), dir.to_string_lossy().to_string()))
+        })
+        .collect();
+
+    if let Some(public_dir) = detect_public_dir(&path.to_path_buf()) {
+        directories.insert("public".to_string(), public_dir);
+    }
+
+    directories
+}
+
+/// Reads the literal value of `key` in a JS/TS config object (e.g. `output: 'server'`)
+fn extract_config_literal(content: &str, key: &str) -> Option<String> {
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("//") || trimmed.starts_with('*') || trimmed.starts_with("/*") {
+            continue;
+        }
+
+        for (index, _) in line.match_indices(key) {
+            // Skip identifiers that merely end in the key (e.g. `buildOutput`)
+            let preceded_by_ident = line[..index]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            if preceded_by_ident {
+                continue;
+            }
+
+            let Some(value) = line[index + key.len()..].trim_start().strip_prefix(':') else {
+                continue;
+            };
+            let value: String = value
+                .trim_start()
+                .trim_start_matches(['\'', '"', '`'])
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
+                .collect();
+            if !value.is_empty() {
+                return Some(value);
+            }
+        }
+    }
+
+    None
+}
+
+/// Detects how the project is rendered: `static`, `server`, `hybrid` or `spa`
+///
+/// Reads Astro's `output` option, Nuxt's `ssr` flag and the SvelteKit adapter,
+/// falling back to each framework's default when the option isn't set. Returns
+/// None for frameworks without a configurable rendering mode.
+pub fn detect_render_mode(path: &PathBuf) -> Option<String> {
+    let read_config = |files: &[&str]| {
+        files
+            .iter()
+            .find_map(|file| fs::read_to_string(path.join(file)).ok())
+            .unwrap_or_default()
+    };
+
+    match crate::detection::detect_framework_from_path(path) {
+        "astro" => {
+            let config = read_config(&["astro.config.mjs", "astro.config.js", "astro.config.ts"]);
+            // Astro builds a static site unless told otherwise
+            let output = extract_config_literal(&config, "output")
+                .filter(|output| ["static", "server", "hybrid"].contains(&output.as_str()));
+            Some(output.unwrap_or_else(|| "static".to_string()))
+        }
+        "nuxt" => {
+            let config = read_config(&["nuxt.config.ts", "nuxt.config.js"]);
+            // Nuxt server-renders unless `ssr: false` turns the app into an SPA
+            match extract_config_literal(&config, "ssr").as_deref() {
+                Some("false") => Some("spa".to_string()),
+                _ => Some("server".to_string()),
+            }
+        }
+        "sveltekit" => {
+            let config = read_config(&["svelte.config.js", "svelte.config.ts"]);
+            if config.contains("@sveltejs/adapter-static") {
+                Some("static".to_string())
+            } else if config.contains("@sveltejs/adapter-") && !config.contains("adapter-auto") {
+                Some("server".to_string())
+            } else {
+                // adapter-auto picks the mode based on the deployment platform
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Detects whether the project is configured to emit source maps
+///
+/// Checks Vite's `build.sourcemap` (any value but `false` emits maps), Next.js's
+/// `productionBrowserSourceMaps`, then tsconfig's `compilerOptions.sourceMap`.
+/// The first setting found wins; returns None when none of them is set.
+pub fn detect_sourcemaps(path: &Path) -> Result<Option<bool>, AppError> {
+    let bundler_settings: &[(&[&str], &str)] = &[
+        (
+            &["vite.config.ts", "vite.config.js", "vite.config.mjs"],
+            "sourcemap",
+        ),
+        (
+            &["next.config.js", "next.config.mjs", "next.config.ts"],
+            "productionBrowserSourceMaps",
+        ),
+    ];
+    for (files, key) in bundler_settings {
+        let value = files
+            .iter()
+            .filter_map(|file| fs::read_to_string(path.join(file)).ok())
+            .find_map(|config| extract_config_literal(&config, key));
+        if let Some(value) = value {
+            return Ok(Some(value != "false"));
+        }
+    }
+
+    let Ok(content) = fs::read_to_string(path.join("tsconfig.json")) else {
+        return Ok(None);
+    };
+    let config: serde_json::Value = serde_json::from_str(&strip_jsonc(&content))
+        .map_err(|e| AppError::ParseError(format!("Invalid tsconfig.json: {}", e)))?;
+
+    Ok(config
+        .get("compilerOptions")
+        .and_then(|options| options.get("sourceMap"))
+        .and_then(|source_map| source_map.as_bool()))
+}
+
+/// Test runners and the test file globs they use when their config sets none
+const TEST_RUNNERS: &[(&str, &[&str])] = &[
+    ("vitest", &["**/*.{test,spec}.?(c|m)[jt]s?(x)"]),
+    (
+        "jest",
+        &[
+            "**/__tests__/**/*.[jt]s?(x)",
+            "**/?(*.)+(spec|test).[jt]s?(x)",
+        ],
+    ),
+    ("@playwright/test", &["**/*.@(spec|test).?(c|m)[jt]s?(x)"]),
+];
+
+/// Collects the contents of the string literals in a config snippet
+pub fn string_literals(block: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    let mut chars = block.chars();
+
+    while let Some(c) = chars.next() {
+        if matches!(c, '"' | '\'' | '`') {
+            literals.push(chars.by_ref().take_while(|&next| next != c).collect());
+        }
+    }
+
+    literals
+}
+
+/// Reads the test globs set in a runner's config; empty when it sets none
+fn configured_test_globs(path: &Path, runner: &str) -> Vec<String> {
+    let read_config = |files: &[&str]| {
+        files
+            .iter()
+            .find_map(|file| fs::read_to_string(path.join(file)).ok())
+            .map(|content| crate::port::strip_js_comments(&content))
+    };
+
+    match runner {
+        "vitest" => read_config(&[
+            "vitest.config.ts",
+            "vitest.config.mts",
+            "vitest.config.js",
+            "vitest.config.mjs",
+            "vite.config.ts",
+            "vite.config.js",
+            "vite.config.mjs",
+        ])
+        .and_then(|config| {
+            crate::port::find_config_blocks(&config, &["test"])
+                .into_iter()
+                .find_map(|test| {
+                    // coverage.include lists source files, not tests
+                    let test = crate::port::find_config_blocks(test, &["coverage"])
+                        .iter()
+                        .fold(test.to_string(), |test, coverage| {
+                            test.replacen(coverage, "", 1)
+                        });
+                    crate::port::find_config_blocks(&test, &["include"])
+                        .first()
+                        .map(|include| string_literals(include))
+                })
+        })
+        .unwrap_or_default(),
+        "jest" => read_config(&[
+            "jest.config.js",
+            "jest.config.ts",
+            "jest.config.mjs",
+            "jest.config.cjs",
+        ])
+        .and_then(|config| {
+            crate::port::find_config_blocks(&config, &["testMatch"])
+                .first()
+                .map(|test_match| string_literals(test_match))
+        })
+        .or_else(|| {
+            let test_match = read_package_json(path)?
+                .get("jest")?
+                .get("testMatch")?
+                .clone();
+            serde_json::from_value(test_match).ok()
+        })
+        .unwrap_or_default(),
+        "@playwright/test" => read_config(&[
+            "playwright.config.ts",
+            "playwright.config.js",
+            "playwright.config.mjs",
+        ])
+        .and_then(|config| {
+            config.match_indices("testDir").find_map(|(index, key)| {
+                let value = config[index + key.len()..].trim_start().strip_prefix(':')?;
+                string_literals(value.split([',', '\n']).next()?)
+                    .into_iter()
+                    .next()
+            })
+        })
+        .map(|dir| {
+            dir.trim_start_matches("./")
+                .trim_end_matches('/')
+                .to_string()
+        })
+        .filter(|dir| !dir.is_empty() && dir != ".")
+        .map(|dir| vec![format!("{}/**/*.@(spec|test).?(c|m)[jt]s?(x)", dir)])
+        .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+/// Detects where the project's tests live, as globs relative to the project root
+///
+/// Reads vitest's `test.include`, jest's `testMatch` (config file or package.json)
+/// and playwright's `testDir`, falling back to a runner's default patterns when
+/// its config sets none. Covers every runner among the dependencies.
+pub fn detect_test_globs(path: &Path) -> Vec<String> {
+    let dependencies = get_dependency_names(path);
+    let mut globs = Vec::new();
+
+    for (runner, defaults) in TEST_RUNNERS {
+        if !dependencies.iter().any(|name| name == runner) {
+            continue;
+        }
+
+        let configured = configured_test_globs(path, runner);
+        if configured.is_empty() {
+            globs.extend(defaults.iter().map(|glob| glob.to_string()));
+        } else {
+            globs.extend(configured);
+        }
+    }
+
+    globs
+}
+
+/// `@astrojs/*` packages that are deployment adapters rather than UI integrations
+const ASTRO_ADAPTERS: &[&str] = &["vercel", "netlify", "node", "cloudflare", "deno"];
+
+/// Finds the adapter named by an adapter package (`@sveltejs/adapter-vercel` → "vercel")
+fn adapter_from_package(framework: &str, text: &str) -> Option<String> {
+    let package_prefixes: &[&str] = match framework {
+        "sveltekit" => &["@sveltejs/adapter-", "svelte-adapter-"],
+        "astro" => &["@astrojs/"],
+        _ => return None,
+    };
+
+    package_prefixes.iter().find_map(|prefix| {
+        text.match_indices(prefix).find_map(|(index, _)| {
+            let name: String = text[index + prefix.len()..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+                .collect();
+            let is_adapter = !name.is_empty()
+                && (framework != "astro" || ASTRO_ADAPTERS.contains(&name.as_str()));
+            is_adapter.then_some(name)
+        })
+    })
+}
+
+/// Detects the deployment adapter of a SvelteKit or Astro project, or Nuxt's Nitro preset
+///
+/// The adapter imported by the framework config wins; otherwise an adapter
+/// package among the dependencies is used. Returns e.g. "vercel", "netlify"
+/// or "node", and None for frameworks without adapters.
+pub fn detect_adapter(path: &Path, framework: &str) -> Option<String> {
+    let config_files: &[&str] = match framework {
+        "sveltekit" => &["svelte.config.js", "svelte.config.ts"],
+        "astro" => &["astro.config.mjs", "astro.config.js", "astro.config.ts"],
+        "nuxt" => &["nuxt.config.ts", "nuxt.config.js"],
+        _ => return None,
+    };
+    let config = config_files
+        .iter()
+        .find_map(|file| fs::read_to_string(path.join(file)).ok())
+        .unwrap_or_default();
+
+    // Nuxt deploys through Nitro presets rather than adapter packages
+    if framework == "nuxt" {
+        return extract_config_literal(&config, "preset");
+    }
+
+    adapter_from_package(framework, &config).or_else(|| {
+        get_dependency_names(path)
+            .iter()
+            .find_map(|name| adapter_from_package(framework, name))
+    })
+}
+
+/// Directories a framework writes its build output to, in the order they're checked
+fn build_dir_candidates(framework: &str) -> &'static [&'static str] {
+    match framework {
+        "nextjs" => &[".next", "out"],
+        "nuxt" => &[".output", "dist"],
+        // adapter-static writes to build/, other adapters keep output under .svelte-kit
+        "sveltekit" => &["build", ".svelte-kit/output"],
+        "react" => &["build"],
+        "gatsby" => &["public"],
+        _ => &["dist", "build", "out"],
+    }
+}
+
+/// Returns the project's build output directory, if it exists
+pub fn detect_build_dir(path: &PathBuf) -> Option<PathBuf> {
+    let framework = crate::detection::detect_framework_from_path(path);
+
+    build_dir_candidates(framework)
+        .iter()
+        .map(|dir| path.join(dir))
+        .find(|dir| dir.is_dir())
+}
+
+/// Finds the most recent modification time of any file under `path`
+///
+/// Directories for which `skip` returns true aren't descended into.
+fn newest_modified(path: &Path, skip: &dyn Fn(&Path) -> bool) -> Option<SystemTime> {
+    let mut newest = None;
+
+    for entry in fs::read_dir(path).ok()?.flatten() {
+        let entry_path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        let modified = if metadata.is_dir() {
+            if skip(&entry_path) {
+                continue;
+            }
+            newest_modified(&entry_path, skip)
+        } else {
+            metadata.modified().ok()
+        };
+        newest = newest.max(modified);
+    }
+
+    newest
+}
+
+/// Checks whether any source file changed after the last build
+///
+/// Compares the newest file in the build output directory against the newest
+/// file elsewhere in the project, skipping `SKIP_DIRS` and hidden directories
+/// (tool caches like `.svelte-kit` or `.nuxt`). Returns None when there's no
+/// build output.
+pub fn build_is_stale(path: &PathBuf) -> Option<bool> {
+    let build_dir = detect_build_dir(path)?;
+    let built_at = newest_modified(&build_dir, &|_| false)?;
+
+    let is_ignored = |dir: &Path| {
+        dir == build_dir
+            || dir
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|name| name.starts_with('.') || SKIP_DIRS.contains(&name))
+    };
+    let sources_changed_at = newest_modified(path, &is_ignored);
+
+    Some(sources_changed_at.is_some_and(|changed_at| changed_at > built_at))
+}
+
+/// Parses the output of `df -Pk` into total and available bytes
+#[cfg(unix)]
+fn parse_df_output(output: &str) -> Option<DiskInfo> {
+    // POSIX format: Filesystem 1024-blocks Used Available Capacity Mounted-on
+    let line = output.lines().nth(1)?;
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let total_kb: u64 = fields.get(1)?.parse().ok()?;
+    let available_kb: u64 = fields.get(3)?.parse().ok()?;
+
+    Some(DiskInfo {
+        total_bytes: total_kb * 1024,
+        available_bytes: available_kb * 1024,
+    })
+}
+
+/// Reports the free space on the filesystem containing the project
+///
+/// Note: This function uses `df` and will only work on Unix-like systems (Linux, macOS).
+#[cfg(unix)]
+pub fn check_disk_space(path: &Path) -> Result<DiskInfo, AppError> {
+    // -P keeps each filesystem on one line, -k fixes the block size at 1024 bytes
+    let output = StdCommand::new("df").arg("-Pk").arg(path).output()?;
+    if !output.status.success() {
+        return Err(AppError::CommandError(format!(
+            "df failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    parse_df_output(&String::from_utf8(output.stdout)?)
+        .ok_or_else(|| AppError::ParseError("Unexpected df output".to_string()))
+}
+
+/// Reports the free space on the filesystem containing the project; not supported on Windows
+#[cfg(windows)]
+pub fn check_disk_space(_path: &Path) -> Result<DiskInfo, AppError> {
+    Err(AppError::CommandError(
+        "Checking disk space is not supported on Windows".to_string(),
+    ))
+}
+
+/// Version control markers and the system they belong to
+const VCS_MARKERS: &[(&str, &str)] = &[(".git", "git"), (".hg", "mercurial"), (".svn", "svn")];
+
+/// Detects the version control system the project directory is under
+///
+/// Only the project directory itself is checked: a repository further up
+/// (e.g. dotfiles tracked in `~/.git`) doesn't mean the project is versioned.
+/// `.git` may be a file (worktrees and submodules) rather than a directory.
+pub fn detect_vcs(path: &Path) -> Option<String> {
+    VCS_MARKERS
+        .iter()
+        .find(|(marker, _)| path.join(marker).exists())
+        .map(|(_, vcs)| vcs.to_string())
+}
+
+/// Collects up to `limit` file paths under `root`, skipping `SKIP_DIRS`
+fn sample_files(root: &Path, limit: usize) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let entry_path = entry.path();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+
+            if file_type.is_dir() {
+                let skipped = entry_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|name| SKIP_DIRS.contains(&name));
+                if !skipped {
+                    pending.push(entry_path);
+                }
+                continue;
+            }
+
+            if files.len() >= limit {
+                return files;
+            }
+            files.push(entry_path);
+        }
+    }
+
+    files
+}
+
+/// File extensions reported as source kinds, beyond plain TS/JS
+const SOURCE_KIND_EXTENSIONS: &[&str] = &["tsx", "jsx", "vue", "svelte", "astro"];
+
+/// Maximum number of files inspected under src/ when sampling source kinds
+const MAX_SOURCE_SAMPLE_FILES: usize = 2000;
+
+/// Samples `src/` for component file types (`.tsx`, `.jsx`, `.vue`, `.svelte`, `.astro`)
+///
+/// Returns the kinds present, most common first. Only the first
+/// `MAX_SOURCE_SAMPLE_FILES` files are inspected so huge trees stay cheap.
+pub fn get_source_kinds(path: &Path) -> Vec<String> {
+    let mut counts = [0usize; SOURCE_KIND_EXTENSIONS.len()];
+
+    for file in sample_files(&path.join("src"), MAX_SOURCE_SAMPLE_FILES) {
+        let extension = file.extension().and_then(|e| e.to_str());
+        if let Some(index) = SOURCE_KIND_EXTENSIONS
+            .iter()
+            .position(|kind| Some(*kind) == extension)
+        {
+            counts[index] += 1;
+        }
+    }
+
+    let mut kinds: Vec<(usize, &str)> = counts
+        .iter()
+        .zip(SOURCE_KIND_EXTENSIONS)
+        .filter(|(count, _)| **count > 0)
+        .map(|(count, kind)| (*count, *kind))
+        .collect();
+    // Stable sort keeps SOURCE_KIND_EXTENSIONS order for ties
+    kinds.sort_by_key(|(count, _)| std::cmp::Reverse(*count));
+
+    kinds
+        .into_iter()
+        .map(|(_, kind)| kind.to_string())
+        .collect()
+}
+
+/// How long a cached size is trusted; edits deep in the tree don't change the fingerprint
+const DIRECTORY_SIZE_TTL: Duration = Duration::from_secs(300);
+
+/// A computed directory size and the fingerprint it was computed for
+#[derive(Clone, Copy)]
+struct CachedSize {
+    fingerprint: SystemTime,
+    computed_at: Instant,
+    size: u64,
+}
+
+// Cache directory sizes so unchanged projects aren't re-walked on every scan
+static DIRECTORY_SIZE_CACHE: OnceLock<std::sync::Mutex<HashMap<PathBuf, CachedSize>>> =
+    OnceLock::new();
+
+fn get_directory_size_cache() -> &'static std::sync::Mutex<HashMap<PathBuf, CachedSize>> {
+    DIRECTORY_SIZE_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+}
+
+/// The newest modified time of the directory and its top-level entries
+///
+/// Sees files added, removed or edited at the top level (including an
+/// in-place `.gitignore` edit) and changes one level down.
+fn directory_size_fingerprint(path: &Path) -> Option<SystemTime> {
+    let modified = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()?;
+    let newest_entry = fs::read_dir(path)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+        .max();
+    Some(newest_entry.map_or(modified, |entry| entry.max(modified)))
+}
+
+/// Returns the project's size in bytes
+///
+/// The size is cached per path and reused until the directory's fingerprint
+/// (see `directory_size_fingerprint`) changes or `DIRECTORY_SIZE_TTL` passes,
+/// so re-scanning an unchanged project skips the tree walk.
+pub fn get_directory_size(path: &PathBuf) -> Option<u64> {
+    let Some(fingerprint) = directory_size_fingerprint(path) else {
+        return compute_directory_size(path);
+    };
+
+    let cached = get_directory_size_cache()
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(path).copied());
+    if let Some(cached) = cached {
+        if cached.fingerprint == fingerprint && cached.computed_at.elapsed() < DIRECTORY_SIZE_TTL {
+            return Some(cached.size);
+        }
+    }
+
+    // Walk without holding the lock so parallel scans don't serialize on it
+    let size = compute_directory_size(path)?;
+    if let Ok(mut cache) = get_directory_size_cache().lock() {
+        // Drop expired entries so projects that are no longer scanned don't pile up
+        cache.retain(|_, cached| cached.computed_at.elapsed() < DIRECTORY_SIZE_TTL);
+        cache.insert(
+            path.clone(),
+            CachedSize {
+                fingerprint,
+                computed_at: Instant::now(),
+                size,
+            },
+        );
+    }
+    Some(size)
+}
+
+/// A `.gitignore` pattern, reduced to what's needed to skip paths while sizing
+struct IgnorePattern {
+    glob: String,
+    /// Matched against the path relative to the project root instead of the name
+    anchored: bool,
+    /// Trailing `/`: only matches directories
+    dir_only: bool,
+}
+
+impl IgnorePattern {
+    fn matches(&self, relative_path: &str, name: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        let target = if self.anchored { relative_path } else { name };
+        glob_matches(&self.glob, target)
+    }
+}
+
+/// Matches `text` against a glob where `*` matches any run of characters and `?` one
+pub fn glob_matches(glob: &str, text: &str) -> bool {
+    let glob: Vec<char> = glob.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut g, mut t) = (0, 0);
+    // Position of the last `*` and the text index it's currently matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if g < glob.len() && (glob[g] == '?' || glob[g] == text[t]) {
+            g += 1;
+            t += 1;
+        } else if g < glob.len() && glob[g] == '*' {
+            backtrack = Some((g, t));
+            g += 1;
+        } else if let Some((star, matched)) = backtrack {
+            g = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+
+    glob[g..].iter().all(|&c| c == '*')
+}
+
+/// Reads the patterns of the project's root `.gitignore`
+///
+/// Negations (`!pattern`) aren't supported and are dropped, so re-included
+/// paths stay skipped. Returns None when there's no `.gitignore`.
+fn read_gitignore(path: &Path) -> Option<Vec<IgnorePattern>> {
+    let content = fs::read_to_string(path.join(".gitignore")).ok()?;
+
+    let patterns = content
+        .lines()
+        .map(|line| line.trim_end())
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('!'))
+        .map(|line| {
+            let dir_only = line.ends_with('/');
+            let line = line.trim_end_matches('/');
+            // `**/name` matches at any depth, like a bare name
+            let line = line.strip_prefix("**/").unwrap_or(line);
+            IgnorePattern {
+                anchored: line.contains('/'),
+                glob: line.trim_start_matches('/').to_string(),
+                dir_only,
+            }
+        })
+        .filter(|pattern| !pattern.glob.is_empty())
+        .collect();
+
+    Some(patterns)
+}
+
+/// Walks the tree summing file sizes
+///
+/// Paths ignored by the project's `.gitignore` (and `.git` itself) are skipped;
+/// without a `.gitignore`, `SKIP_DIRS` is skipped instead.
+fn compute_directory_size(path: &PathBuf) -> Option<u64> {
+    let mut total_size = 0u64;
+    let gitignore = read_gitignore(path);
+    let is_skipped = |entry_path: &Path, name: &str, is_dir: bool| match &gitignore {
+        Some(patterns) => {
+            let relative_path = entry_path
+                .strip_prefix(path)
+                .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_default();
+            (is_dir && name == ".git")
+                || patterns
+                    .iter()
+                    .any(|pattern| pattern.matches(&relative_path, name, is_dir))
+        }
+        None => is_dir && SKIP_DIRS.contains(&name),
+    };
+    
+    calculate_size(path, &mut total_size, &is_skipped);
+    Some(total_size)
+}
+
+/// Adds up the size of every file under `path`
+///
+/// Entries for which `is_skipped(path, name, is_dir)` returns true are left out.
+fn calculate_size(path: &Path, total: &mut u64, is_skipped: &dyn Fn(&Path, &str, bool) -> bool) {
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            let entry_path = entry.path();
+            
+            // Use metadata() which is faster than separate is_dir() + metadata() calls
+            if let Ok(metadata) = entry_path.metadata() {
+                // Skip large directories to speed up calculation
+                if let Some(name) = entry_path.file_name().and_then(|n| n.to_str()) {
+                    if is_skipped(&entry_path, name, metadata.is_dir()) {
+                        continue;
+                    }
+                }
+                if metadata.is_dir() {
+                    calculate_size(&entry_path, total, is_skipped);
+                } else {
+                    *total += metadata.len();
+                }
+            }
+        }
+    }
+}
+
+/// Returns the total size of the project's build output, or None when it hasn't been built
+///
+/// Unlike the project size, nothing under the build directory is skipped.
+pub fn build_output_size(path: &PathBuf) -> Option<u64> {
+    let build_dir = detect_build_dir(path)?;
+    let mut total_size = 0u64;
+    calculate_size(&build_dir, &mut total_size, &|_, _, _| false);
+    Some(total_size)
+}
+
+pub fn get_modified_time(path: &PathBuf) -> Option<i64> {
+    if let Ok(metadata) = fs::metadata(path) {
+        if let Ok(modified) = metadata.modified() {
+            if let Ok(duration) = modified.duration_since(std::time::UNIX_EPOCH) {
+                return Some(duration.as_secs() as i64);
+            }
+        }
+    }
+    None
+}
+
+/// Converts a file's creation (birth) time to Unix seconds
+///
+/// Returns None on platforms or filesystems that don't record it.
+fn created_time(metadata: &fs::Metadata) -> Option<i64> {
+    let created = metadata.created().ok()?;
+    let duration = created.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(duration.as_secs() as i64)
+}
+
+/// Returns when the project was created, as Unix seconds
+///
+/// Uses the directory's creation time where the platform supports it, and the
+/// date of the first git commit otherwise.
+pub fn get_created_time(path: &Path) -> Option<i64> {
+    fs::metadata(path)
+        .ok()
+        .and_then(|metadata| created_time(&metadata))
+        .or_else(|| crate::git::first_commit_time(path))
+}
+
+pub fn enrich_project_info(mut project: Project) -> Project {
+    let path = PathBuf::from(&project.path);
+    
+    // Get runtime version
+    project.runtime_version = get_runtime_version(&project.runtime, &path);
+    project.pinned_runtime_version = get_pinned_runtime_version(&project.runtime, &path);
+    
+    // Get scripts and platform constraints from package.json (only for Node.js/Bun projects)
+    if project.runtime == "Node.js" || project.runtime == "Bun" {
+        if let Some(package_manager) = project.package_manager.as_deref() {
+            project.package_manager_version = get_package_manager_version(package_manager, &path);
+            project.package_manager_deprecated = is_package_manager_deprecated(
+                package_manager,
+                project.package_manager_version.as_deref(),
+            );
+        }
+        project.scripts = get_package_json_scripts(&path);
+        project.node_flags = project
+            .scripts
+            .as_ref()
+            .map(get_node_flags)
+            .unwrap_or_default();
+        project.platform_constraints = get_platform_constraints(&path);
+        project.bin_names = get_bin_names(&path);
+        project.has_bin = !project.bin_names.is_empty();
+        project.uses_https = project
+            .framework
+            .as_deref()
+            .and_then(|framework| crate::port::detect_https(&path, framework));
+        project.preferred_host = project
+            .framework
+            .as_deref()
+            .and_then(|framework| crate::port::detect_preferred_host(&path, framework));
+        project.ports = crate::port::detect_server_ports(&path);
+        project.hmr_port =
+            crate::port::detect_hmr_port(&path).filter(|&hmr_port| Some(hmr_port) != project.port);
+        project.version_manager = get_version_manager(&path);
+        project.css_framework = get_css_framework(&path);
+        project.api_framework = get_api_framework(&path);
+        project.auth_lib = get_auth_lib(&path);
+        project.data_libs = get_data_libs(&path);
+        project.adapter = project
+            .framework
+            .as_deref()
+            .and_then(|framework| detect_adapter(&path, framework));
+        project.router_type = project
+            .framework
+            .as_deref()
+            .and_then(|framework| get_router_type(&path, framework));
+        project.telemetry = get_telemetry(&path);
+        project.next_telemetry_disabled = get_next_telemetry_disabled(&path);
+        project.dev_verbosity = get_dev_verbosity(&path);
+        project.node_memory_limit_mb = get_node_memory_limit_mb(&path);
+        project.content_source = get_content_source(&path);
+        project.in_workspace = crate::workspace::in_workspace(&path);
+    }
+    
+    project.requires_database = requires_database(&path);
+    
+    project.source_kinds = get_source_kinds(&path);
+    project.has_devcontainer = has_devcontainer(&path);
+    project.git_hooks = get_git_hooks(&path);
+    project.vcs = detect_vcs(&path);
+    project.version_controlled = project.vcs.is_some();
+    project.is_fork = crate::git::is_fork(&path);
+    project.upstream_url = crate::git::get_remote_url(&path, "upstream");
+    project.ready_pattern = project
+        .framework
+        .as_deref()
+        .and_then(crate::port::ready_pattern_for);
+    
+    // Get directory size
+    project.size = get_directory_size(&path);
+    
+    // Get modified time
+    project.modified = get_modified_time(&path);
+    project.created = get_created_time(&path);
+    
+    project
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_temp_dir() -> TempDir {
+        tempfile::tempdir().expect("Failed to create temp directory")
+    }
+
+    fn create_temp_file(dir: &std::path::Path, name: &str, content: &str) -> PathBuf {
+        let file_path = dir.join(name);
+        fs::write(&file_path, content).expect("Failed to write temp file");
+        file_path
+    }
+
+    #[test]
+    fn test_get_platform_constraints() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"os": ["darwin"], "cpu": ["arm64"]}"#,
+        );
+
+        assert_eq!(
+            get_platform_constraints(&dir_path),
+            Some(PlatformConstraints {
+                os: vec!["darwin".to_string()],
+                cpu: vec!["arm64".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_bin_names_string_form() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"name": "@acme/deploy", "bin": "./dist/cli.js"}"#,
+        );
+
+        assert_eq!(get_bin_names(temp_dir.path()), vec!["deploy"]);
+    }
+
+    #[test]
+    fn test_get_bin_names_object_form() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"name": "tools", "bin": {"tools": "./bin/tools.js", "tools-init": "./bin/init.js"}}"#,
+        );
+
+        assert_eq!(get_bin_names(temp_dir.path()), vec!["tools", "tools-init"]);
+    }
+
+    #[test]
+    fn test_get_platform_constraints_os_only() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "package.json", r#"{"os": ["!win32"]}"#);
+
+        let constraints = get_platform_constraints(&dir_path).expect("Expected constraints");
+        assert_eq!(constraints.os, vec!["!win32".to_string()]);
+        assert!(constraints.cpu.is_empty());
+    }
+
+    #[test]
+    fn test_get_platform_constraints_unconstrained() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "package.json", r#"{"name": "app"}"#);
+
+        assert_eq!(get_platform_constraints(&dir_path), None);
+    }
+
+    #[test]
+    fn test_get_volta_pins() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"volta": {"node": "20.11.0", "npm": "10.2.4"}}"#,
+        );
+
+        let pins = get_volta_pins(temp_dir.path());
+        assert_eq!(pins.get("node"), Some(&"20.11.0".to_string()));
+        assert_eq!(pins.get("npm"), Some(&"10.2.4".to_string()));
+    }
+
+    #[test]
+    fn test_get_pinned_runtime_version_volta_node() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"volta": {"node": "20.11.0"}}"#,
+        );
+
+        assert_eq!(
+            get_pinned_runtime_version("Node.js", temp_dir.path()),
+            Some("20.11.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_pinned_runtime_version_precedence() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), ".nvmrc", "18\n");
+        create_temp_file(temp_dir.path(), ".tool-versions", "nodejs 19.0.0\n");
+        assert_eq!(
+            get_pinned_runtime_version("Node.js", temp_dir.path()),
+            Some("19.0.0".to_string()),
+            ".tool-versions should win over .nvmrc"
+        );
+
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"volta": {"node": "20.11.0"}}"#,
+        );
+        assert_eq!(
+            get_pinned_runtime_version("Node.js", temp_dir.path()),
+            Some("20.11.0".to_string()),
+            "volta should win over .tool-versions"
+        );
+    }
+
+    #[test]
+    fn test_get_pinned_runtime_version_package_manager_field() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"packageManager": "bun@1.1.0+sha512.abc", "volta": {"bun": "1.0.0"}}"#,
+        );
+
+        assert_eq!(
+            get_pinned_runtime_version("Bun", temp_dir.path()),
+            Some("1.1.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_pinned_runtime_version_none() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), "package.json", r#"{}"#);
+
+        assert_eq!(get_pinned_runtime_version("Node.js", temp_dir.path()), None);
+    }
+
+    #[test]
+    fn test_get_version_manager_markers() {
+        let cases = [
+            (".mise.toml", "[tools]\nnode = \"20\"", "mise"),
+            ("mise.toml", "[tools]\nnode = \"20\"", "mise"),
+            (".tool-versions", "nodejs 20.11.0", "asdf"),
+            (".nvmrc", "20", "nvm"),
+            (".node-version", "20.11.0", "fnm"),
+            ("package.json", r#"{"volta": {"node": "20.11.0"}}"#, "volta"),
+        ];
+
+        for (file, content, expected) in cases {
+            let temp_dir = create_temp_dir();
+            create_temp_file(temp_dir.path(), file, content);
+            assert_eq!(
+                get_version_manager(temp_dir.path()),
+                Some(expected.to_string()),
+                "{} should map to {}",
+                file,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_version_manager_prefers_most_specific() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), ".nvmrc", "20");
+        create_temp_file(temp_dir.path(), ".tool-versions", "nodejs 20.11.0");
+        assert_eq!(
+            get_version_manager(temp_dir.path()),
+            Some("asdf".to_string())
+        );
+
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"volta": {"node": "20.11.0"}}"#,
+        );
+        assert_eq!(
+            get_version_manager(temp_dir.path()),
+            Some("volta".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_version_manager_none() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), "package.json", r#"{"name": "app"}"#);
+        assert_eq!(get_version_manager(temp_dir.path()), None);
+    }
+
+    #[test]
+    fn test_documented_commands_from_readme() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "README.md",
+            r#"# My App
+
+Install and run:
+
+```bash
+$ pnpm install
+$ node scripts/seed.js --force
+cd packages/web
+```
+
+Configuration example:
+
+```js
+node.start()
+```
+
+Run the worker:
+
+```
+npx tsx worker.ts
+pnpm install
+```
+"#,
+        );
+
+        assert_eq!(
+            documented_commands(temp_dir.path()),
+            vec![
+                "pnpm install".to_string(),
+                "node scripts/seed.js --force".to_string(),
+                "npx tsx worker.ts".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_documented_commands_without_readme() {
+        let temp_dir = create_temp_dir();
+        assert!(documented_commands(temp_dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_strip_jsonc() {
+        let content = r#"{
+            // line comment
+            "url": "http://example.com", /* block */
+            "list": [1, 2,],
+            "text": "keep // this, ]",
+        }"#;
+
+        let value: serde_json::Value = serde_json::from_str(&strip_jsonc(content)).unwrap();
+        assert_eq!(value["url"], "http://example.com");
+        assert_eq!(value["list"], serde_json::json!([1, 2]));
+        assert_eq!(value["text"], "keep // this, ]");
+    }
+
+    #[test]
+    fn test_devcontainer_info_forward_ports() {
+        let temp_dir = create_temp_dir();
+        fs::create_dir(temp_dir.path().join(".devcontainer")).unwrap();
+        create_temp_file(
+            &temp_dir.path().join(".devcontainer"),
+            "devcontainer.json",
+            r#"{
+                // Generated by VS Code
+                "name": "Node.js & PostgreSQL",
+                "image": "mcr.microsoft.com/devcontainers/javascript-node:20",
+                "forwardPorts": [3000, "db:5432"],
+            }"#,
+        );
+
+        assert!(has_devcontainer(temp_dir.path()));
+        assert_eq!(
+            devcontainer_info(temp_dir.path()).unwrap(),
+            DevcontainerInfo {
+                name: Some("Node.js & PostgreSQL".to_string()),
+                forward_ports: vec!["3000".to_string(), "db:5432".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_devcontainer_info_missing() {
+        let temp_dir = create_temp_dir();
+        assert!(!has_devcontainer(temp_dir.path()));
+        assert!(matches!(
+            devcontainer_info(temp_dir.path()),
+            Err(AppError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_bundle_report_next_analyze() {
+        let temp_dir = create_temp_dir();
+        assert_eq!(find_bundle_report(temp_dir.path()), None);
+
+        fs::create_dir_all(temp_dir.path().join(".next/analyze")).unwrap();
+        create_temp_file(
+            temp_dir.path(),
+            ".next/analyze/nodejs.html",
+            "<html></html>",
+        );
+        create_temp_file(
+            temp_dir.path(),
+            ".next/analyze/client.html",
+            "<html></html>",
+        );
+        assert_eq!(
+            find_bundle_report(temp_dir.path()),
+            Some(".next/analyze/client.html".to_string())
+        );
+    }
+
+    #[test]
+    fn test_find_bundle_report_visualizer_and_webpack() {
+        let temp_dir = create_temp_dir();
+        fs::create_dir(temp_dir.path().join("build")).unwrap();
+        create_temp_file(temp_dir.path(), "build/report.html", "<html></html>");
+        assert_eq!(
+            find_bundle_report(temp_dir.path()),
+            Some("build/report.html".to_string())
+        );
+
+        fs::create_dir(temp_dir.path().join("dist")).unwrap();
+        create_temp_file(temp_dir.path(), "dist/stats.html", "<html></html>");
+        assert_eq!(
+            find_bundle_report(temp_dir.path()),
+            Some("dist/stats.html".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_git_hooks_husky() {
+        let temp_dir = create_temp_dir();
+        assert_eq!(get_git_hooks(temp_dir.path()), None);
+
+        fs::create_dir(temp_dir.path().join(".husky")).unwrap();
+        create_temp_file(temp_dir.path(), ".husky/pre-commit", "npx lint-staged\n");
+        assert_eq!(get_git_hooks(temp_dir.path()), Some("husky".to_string()));
+    }
+
+    #[test]
+    fn test_get_git_hooks_lefthook() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "lefthook.yml",
+            "pre-commit:\n  commands:\n    lint:\n      run: npm run lint\n",
+        );
+        assert_eq!(get_git_hooks(temp_dir.path()), Some("lefthook".to_string()));
+    }
+
+    #[test]
+    fn test_get_git_hooks_simple_git_hooks() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"simple-git-hooks": {"pre-commit": "npx lint-staged"}}"#,
+        );
+        assert_eq!(
+            get_git_hooks(temp_dir.path()),
+            Some("simple-git-hooks".to_string())
+        );
+    }
+
+    #[test]
+    fn test_public_dir_candidates() {
+        assert_eq!(public_dir_candidates("vite"), &["public"]);
+        assert_eq!(public_dir_candidates("nextjs"), &["public"]);
+        assert_eq!(public_dir_candidates("sveltekit"), &["static"]);
+        assert_eq!(public_dir_candidates("nuxt"), &["public", "static"]);
+    }
+
+    #[test]
+    fn test_detect_public_dir_sveltekit() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "svelte.config.js", "");
+
+        // public/ isn't where SvelteKit serves assets from
+        fs::create_dir(dir_path.join("public")).unwrap();
+        assert_eq!(detect_public_dir(&dir_path), None);
+
+        fs::create_dir(dir_path.join("static")).unwrap();
+        assert_eq!(
+            detect_public_dir(&dir_path),
+            Some(dir_path.join("static").to_string_lossy().to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_size_budget_angular() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "angular.json",
+            r#"{
+                "projects": {
+                    "app": {
+                        "architect": {
+                            "build": {
+                                "configurations": {
+                                    "production": {
+                                        "budgets": [
+                                            {
+                                                "type": "initial",
+                                                "maximumWarning": "500kb",
+                                                "maximumError": "1mb"
+                                            },
+                                            {
+                                                "type": "anyComponentStyle",
+                                                "maximumWarning": "2kb"
+                                            },
+                                            {
+                                                "type": "bundle",
+                                                "name": "vendor",
+                                                "maximumError": "300kb"
+                                            }
+                                        ]
+                                    },
+                                    "development": {}
+                                }
+                            }
+                        }
+                    }
+                }
+            }"#,
+        );
+
+        let budgets = detect_size_budget(temp_dir.path()).unwrap().unwrap();
+        assert_eq!(budgets.len(), 3);
+        assert_eq!(
+            budgets[0],
+            BudgetEntry {
+                source: "angular".to_string(),
+                target: "initial".to_string(),
+                max_warning: Some("500kb".to_string()),
+                max_error: Some("1mb".to_string()),
+            }
+        );
+        assert_eq!(budgets[1].max_error, None);
+        assert_eq!(budgets[2].target, "bundle:vendor");
+    }
+
+    #[test]
+    fn test_detect_size_budget_bundlesize() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{
+                "bundlesize": [
+                    { "path": "./dist/app-*.js", "maxSize": "10 kB", "compression": "gzip" },
+                    { "path": "./dist/vendor.js" }
+                ]
+            }"#,
+        );
+
+        let budgets = detect_size_budget(temp_dir.path()).unwrap().unwrap();
+        assert_eq!(
+            budgets,
+            vec![
+                BudgetEntry {
+                    source: "bundlesize".to_string(),
+                    target: "./dist/app-*.js".to_string(),
+                    max_warning: None,
+                    max_error: Some("10 kB".to_string()),
+                },
+                BudgetEntry {
+                    source: "bundlesize".to_string(),
+                    target: "./dist/vendor.js".to_string(),
+                    max_warning: None,
+                    max_error: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_size_budget_none_configured() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), "package.json", r#"{"name": "app"}"#);
+
+        assert_eq!(detect_size_budget(temp_dir.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_detect_import_aliases_tsconfig_paths() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "tsconfig.json",
+            r#"{
+                // Generated by the framework CLI
+                "compilerOptions": {
+                    "baseUrl": ".",
+                    "paths": {
+                        "@/*": ["./src/*"],
+                        "~components/*": ["src/components/*", "src/legacy/*"],
+                    },
+                },
+            }"#,
+        );
+
+        let aliases = detect_import_aliases(temp_dir.path()).unwrap();
+        assert_eq!(aliases.len(), 2);
+        assert_eq!(aliases["@/*"], "src/*");
+        assert_eq!(aliases["~components/*"], "src/components/*");
+    }
+
+    #[test]
+    fn test_detect_import_aliases_relative_to_base_url() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "jsconfig.json",
+            r#"{"compilerOptions": {"baseUrl": "./src", "paths": {"@lib": ["lib/index.js"]}}}"#,
+        );
+
+        let aliases = detect_import_aliases(temp_dir.path()).unwrap();
+        assert_eq!(aliases["@lib"], "src/lib/index.js");
+    }
+
+    #[test]
+    fn test_detect_import_aliases_none_configured() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "tsconfig.json",
+            r#"{"compilerOptions": {"strict": true}}"#,
+        );
+
+        assert!(detect_import_aliases(temp_dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_extract_node_flags() {
+        assert_eq!(
+            extract_node_flags("node --import tsx --watch src/server.ts"),
+            vec!["--import tsx"]
+        );
+        assert_eq!(
+            extract_node_flags(
+                "node --loader ts-node/esm --experimental-specifier-resolution=node index.ts"
+            ),
+            vec![
+                "--loader ts-node/esm",
+                "--experimental-specifier-resolution=node"
+            ]
+        );
+        assert_eq!(
+            extract_node_flags(
+                "tsc && /usr/bin/node --import=tsx/esm dist/main.js --experimental-vm-modules"
+            ),
+            vec!["--import=tsx/esm"]
+        );
+        assert!(extract_node_flags("vite dev --port 3000").is_empty());
+        assert!(extract_node_flags("node server.js").is_empty());
+    }
+
+    #[test]
+    fn test_get_node_flags_from_dev_and_start() {
+        let scripts: HashMap<String, String> = [
+            ("dev", "node --import tsx --watch src/index.ts"),
+            ("start", "node --import tsx src/index.ts"),
+            ("test", "node --experimental-test-coverage --test"),
+        ]
+        .iter()
+        .map(|(name, script)| (name.to_string(), script.to_string()))
+        .collect();
+
+        assert_eq!(get_node_flags(&scripts), vec!["--import tsx"]);
+    }
+
+    #[test]
+    fn test_get_package_manager_version_yarn_berry() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), "yarn.lock", "");
+        create_temp_file(temp_dir.path(), ".yarnrc.yml", "nodeLinker: node-modules\n");
+
+        let version = get_package_manager_version("yarn", temp_dir.path());
+        assert_eq!(version.as_deref(), Some("2+"));
+        assert_eq!(
+            is_package_manager_deprecated("yarn", version.as_deref()),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_get_package_manager_version_yarn_classic() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), "yarn.lock", "# yarn lockfile v1\n");
+        create_temp_file(
+            temp_dir.path(),
+            ".yarnrc",
+            "registry \"https://registry.npmjs.org\"\n",
+        );
+
+        let version = get_package_manager_version("yarn", temp_dir.path());
+        assert_eq!(version.as_deref(), Some("1"));
+        assert_eq!(
+            is_package_manager_deprecated("yarn", version.as_deref()),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_get_package_manager_version_pinned() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"packageManager": "yarn@1.22.19+sha1.abc"}"#,
+        );
+        create_temp_file(temp_dir.path(), ".yarnrc.yml", "");
+
+        // The pin wins over config file heuristics
+        let version = get_package_manager_version("yarn", temp_dir.path());
+        assert_eq!(version.as_deref(), Some("1.22.19"));
+        assert_eq!(
+            is_package_manager_deprecated("yarn", version.as_deref()),
+            Some(true)
+        );
+
+        assert_eq!(get_package_manager_version("npm", temp_dir.path()), None);
+        assert_eq!(is_package_manager_deprecated("npm", None), None);
+    }
+
+    #[test]
+    fn test_get_source_kinds_mixed_src() {
+        let temp_dir = create_temp_dir();
+        let src = temp_dir.path().join("src");
+        fs::create_dir_all(src.join("components")).unwrap();
+        fs::create_dir_all(src.join("node_modules")).unwrap();
+        create_temp_file(&src, "main.ts", "");
+        create_temp_file(&src, "App.vue", "");
+        create_temp_file(&src.join("components"), "Button.vue", "");
+        create_temp_file(&src.join("components"), "Chart.tsx", "");
+        create_temp_file(&src.join("node_modules"), "Ignored.svelte", "");
+
+        assert_eq!(get_source_kinds(temp_dir.path()), vec!["vue", "tsx"]);
+    }
+
+    #[test]
+    fn test_get_source_kinds_without_src() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), "index.jsx", "");
+
+        assert!(get_source_kinds(temp_dir.path()).is_empty());
+    }
+
+    fn set_modified(path: &Path, modified: SystemTime) {
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .and_then(|file| file.set_modified(modified))
+            .expect("Failed to set modified time");
+    }
+
+    #[test]
+    fn test_build_is_stale() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        let now = SystemTime::now();
+        let hour = std::time::Duration::from_secs(3600);
+
+        fs::create_dir_all(dir_path.join("src")).unwrap();
+        fs::create_dir_all(dir_path.join("dist")).unwrap();
+        fs::create_dir_all(dir_path.join("node_modules")).unwrap();
+        let source = create_temp_file(&dir_path.join("src"), "main.ts", "");
+        let bundle = create_temp_file(&dir_path.join("dist"), "index.js", "");
+        let dependency = create_temp_file(&dir_path.join("node_modules"), "dep.js", "");
+
+        set_modified(&source, now - hour * 2);
+        set_modified(&bundle, now - hour);
+        // Installing dependencies doesn't make the build stale
+        set_modified(&dependency, now);
+        assert_eq!(build_is_stale(&dir_path), Some(false));
+
+        set_modified(&source, now);
+        assert_eq!(build_is_stale(&dir_path), Some(true));
+    }
+
+    #[test]
+    fn test_build_is_stale_without_build() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "index.js", "");
+
+        assert_eq!(build_is_stale(&dir_path), None);
+    }
+
+    #[test]
+    fn test_required_env_vars_from_env_example() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            ".env.example",
+            "# Database\n\
+             DATABASE_URL=postgres://localhost:5432/app\n\
+             export NEXTAUTH_SECRET=\n\
+             \n\
+             STRIPE_KEY = sk_test_123 # test key\n\
+             # OPTIONAL_FLAG=true\n\
+             DATABASE_URL=duplicate\n",
+        );
+        create_temp_file(temp_dir.path(), ".env.sample", "SENTRY_DSN=\n");
+        // Real env files are never read
+        create_temp_file(temp_dir.path(), ".env", "SECRET_ONLY_IN_ENV=hunter2\n");
+
+        assert_eq!(
+            required_env_vars(temp_dir.path()),
+            vec![
+                "DATABASE_URL",
+                "NEXTAUTH_SECRET",
+                "STRIPE_KEY",
+                "SENTRY_DSN"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_required_env_vars_without_template() {
+        let temp_dir = create_temp_dir();
+        assert!(required_env_vars(temp_dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_created_time_matches_metadata() {
+        let temp_dir = create_temp_dir();
+        let file = create_temp_file(temp_dir.path(), "package.json", "{}");
+        let metadata = fs::metadata(&file).unwrap();
+
+        match metadata.created() {
+            Ok(created) => {
+                let expected = created
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs() as i64;
+                assert_eq!(created_time(&metadata), Some(expected));
+                // Just created, so it can't be older than the directory itself
+                assert!(get_created_time(temp_dir.path()).is_some_and(|dir| dir <= expected));
+            }
+            // Filesystems without birth times report nothing rather than a wrong date
+            Err(_) => assert_eq!(created_time(&metadata), None),
+        }
+    }
+
+    #[test]
+    fn test_get_css_framework_from_dependencies() {
+        let cases = [
+            (
+                r#"{"dependencies": {"@chakra-ui/react": "^2.8.0"}}"#,
+                "chakra",
+            ),
+            (
+                r#"{"dependencies": {"styled-components": "^6.0.0"}, "devDependencies": {"sass": "^1.70.0"}}"#,
+                "styled-components",
+            ),
+            (
+                r#"{"dependencies": {"bootstrap": "^5.3.0", "sass": "^1.70.0"}}"#,
+                "bootstrap",
+            ),
+            (r#"{"devDependencies": {"sass": "^1.70.0"}}"#, "sass"),
+            (
+                r#"{"devDependencies": {"tailwindcss": "^3.4.0", "sass": "^1.70.0"}}"#,
+                "tailwind",
+            ),
+        ];
+
+        for (package_json, expected) in cases {
+            let temp_dir = create_temp_dir();
+            create_temp_file(temp_dir.path(), "package.json", package_json);
+            assert_eq!(
+                get_css_framework(temp_dir.path()).as_deref(),
+                Some(expected),
+                "{}",
+                package_json
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_css_framework_config_wins() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"bootstrap": "^5.3.0"}}"#,
+        );
+        create_temp_file(temp_dir.path(), "uno.config.ts", "");
+
+        assert_eq!(
+            get_css_framework(temp_dir.path()).as_deref(),
+            Some("unocss")
+        );
+    }
+
+    #[test]
+    fn test_get_telemetry_from_dependencies() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{
+                "dependencies": {"@sentry/react": "^7.100.0", "posthog-js": "^1.110.0"},
+                "devDependencies": {"@vercel/analytics": "^1.2.0"}
+            }"#,
+        );
+
+        assert_eq!(
+            get_telemetry(temp_dir.path()),
+            vec!["vercel-analytics", "posthog", "sentry"]
+        );
+        assert_eq!(get_next_telemetry_disabled(temp_dir.path()), None);
+    }
+
+    #[test]
+    fn test_get_telemetry_next_enabled_by_default() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"scripts": {"dev": "next dev"}, "dependencies": {"next": "^14.1.0"}}"#,
+        );
+
+        assert_eq!(get_next_telemetry_disabled(temp_dir.path()), Some(false));
+        assert_eq!(get_telemetry(temp_dir.path()), vec!["next-telemetry"]);
+    }
+
+    #[test]
+    fn test_get_router_type_app_only() {
+        let temp_dir = create_temp_dir();
+        fs::create_dir_all(temp_dir.path().join("src/app")).unwrap();
+        assert_eq!(
+            get_router_type(temp_dir.path(), "nextjs"),
+            Some("app".to_string())
+        );
+        // Only Next.js has these routers
+        assert_eq!(get_router_type(temp_dir.path(), "vite"), None);
+    }
+
+    #[test]
+    fn test_get_router_type_pages_only() {
+        let temp_dir = create_temp_dir();
+        assert_eq!(get_router_type(temp_dir.path(), "nextjs"), None);
+
+        fs::create_dir(temp_dir.path().join("pages")).unwrap();
+        assert_eq!(
+            get_router_type(temp_dir.path(), "nextjs"),
+            Some("pages".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_router_type_both() {
+        let temp_dir = create_temp_dir();
+        fs::create_dir(temp_dir.path().join("app")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("src/pages")).unwrap();
+        assert_eq!(
+            get_router_type(temp_dir.path(), "nextjs"),
+            Some("both".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_dev_verbosity_debug_namespace() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"scripts": {"dev": "DEBUG=app:* nodemon server.js"}}"#,
+        );
+
+        assert_eq!(
+            get_dev_verbosity(temp_dir.path()),
+            Some("DEBUG=app:*".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_dev_verbosity_verbose_flag() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"scripts": {"dev": "vite --verbose --port 5173"}}"#,
+        );
+        assert_eq!(
+            get_dev_verbosity(temp_dir.path()),
+            Some("--verbose".to_string())
+        );
+
+        let quiet_dir = create_temp_dir();
+        create_temp_file(
+            quiet_dir.path(),
+            "package.json",
+            r#"{"scripts": {"dev": "vite"}}"#,
+        );
+        create_temp_file(quiet_dir.path(), ".env", "DEBUG=false\n");
+        assert_eq!(get_dev_verbosity(quiet_dir.path()), None);
+    }
+
+    #[test]
+    fn test_get_telemetry_next_disabled() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"scripts": {"build": "NEXT_TELEMETRY_DISABLED=1 next build"}, "dependencies": {"next": "^14.1.0"}}"#,
+        );
+        assert_eq!(get_next_telemetry_disabled(temp_dir.path()), Some(true));
+        assert!(get_telemetry(temp_dir.path()).is_empty());
+
+        let env_dir = create_temp_dir();
+        create_temp_file(
+            env_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"next": "^14.1.0"}}"#,
+        );
+        create_temp_file(
+            env_dir.path(),
+            ".env.local",
+            "export NEXT_TELEMETRY_DISABLED=\"1\"\n",
+        );
+        assert_eq!(get_next_telemetry_disabled(env_dir.path()), Some(true));
+    }
+
+    #[test]
+    fn test_get_data_libs() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{
+                "dependencies": {
+                    "@reduxjs/toolkit": "^2.2.0",
+                    "react-redux": "^9.1.0",
+                    "@tanstack/react-query": "^5.28.0",
+                    "@trpc/client": "^10.45.0",
+                    "@trpc/react-query": "^10.45.0"
+                },
+                "devDependencies": {"zustand": "^4.5.0"}
+            }"#,
+        );
+
+        assert_eq!(
+            get_data_libs(temp_dir.path()),
+            vec!["react-query", "trpc", "redux", "zustand"]
+        );
+    }
+
+    #[test]
+    fn test_get_data_libs_none() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"react": "^18.2.0"}}"#,
+        );
+
+        assert!(get_data_libs(temp_dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_get_api_framework() {
+        let cases = [
+            (r#"{"dependencies": {"express": "^4.19.0"}}"#, "express"),
+            (r#"{"dependencies": {"fastify": "^4.26.0"}}"#, "fastify"),
+            (r#"{"dependencies": {"hono": "^4.2.0"}}"#, "hono"),
+            (r#"{"dependencies": {"koa": "^2.15.0"}}"#, "koa"),
+            (
+                r#"{"dependencies": {"@nestjs/core": "^10.3.0", "@nestjs/platform-express": "^10.3.0", "express": "^4.19.0"}}"#,
+                "nestjs",
+            ),
+        ];
+
+        for (package_json, expected) in cases {
+            let temp_dir = create_temp_dir();
+            create_temp_file(temp_dir.path(), "package.json", package_json);
+            assert_eq!(
+                get_api_framework(temp_dir.path()).as_deref(),
+                Some(expected),
+                "{}",
+                package_json
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_auth_lib() {
+        let cases = [
+            (r#"{"dependencies": {"next-auth": "^4.24.0"}}"#, "next-auth"),
+            (
+                r#"{"dependencies": {"@auth/sveltekit": "^1.0.0"}}"#,
+                "next-auth",
+            ),
+            (r#"{"dependencies": {"@clerk/nextjs": "^5.0.0"}}"#, "clerk"),
+            (r#"{"dependencies": {"lucia": "^3.2.0"}}"#, "lucia"),
+            (
+                r#"{"dependencies": {"@supabase/supabase-js": "^2.43.0"}}"#,
+                "supabase",
+            ),
+            (
+                r#"{"dependencies": {"passport": "^0.7.0", "express": "^4.19.0"}}"#,
+                "passport",
+            ),
+            (
+                r#"{"dependencies": {"@supabase/supabase-js": "^2.43.0", "@clerk/nextjs": "^5.0.0"}}"#,
+                "clerk",
+            ),
+        ];
+
+        for (package_json, expected) in cases {
+            let temp_dir = create_temp_dir();
+            create_temp_file(temp_dir.path(), "package.json", package_json);
+            assert_eq!(
+                get_auth_lib(temp_dir.path()).as_deref(),
+                Some(expected),
+                "{}",
+                package_json
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_api_framework_none() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"react": "^18.2.0"}}"#,
+        );
+
+        assert_eq!(get_api_framework(temp_dir.path()), None);
+    }
+
+    #[test]
+    fn test_get_css_framework_none() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"dependencies": {"react": "^18.2.0"}}"#,
+        );
+
+        assert_eq!(get_css_framework(temp_dir.path()), None);
+    }
+
+    #[test]
+    fn test_extract_max_old_space_size() {
+        assert_eq!(
+            extract_max_old_space_size(
+                "cross-env NODE_OPTIONS=--max-old-space-size=4096 next build"
+            ),
+            Some(4096)
+        );
+        assert_eq!(
+            extract_max_old_space_size("NODE_OPTIONS='--max_old_space_size 8192' vite"),
+            Some(8192)
+        );
+        assert_eq!(extract_max_old_space_size("node --inspect server.js"), None);
+    }
+
+    #[test]
+    fn test_get_node_memory_limit_mb_from_scripts_and_env() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"scripts": {
+                "dev": "NODE_OPTIONS=--max-old-space-size=4096 next dev",
+                "build": "NODE_OPTIONS=--max-old-space-size=2048 next build"
+            }}"#,
+        );
+        assert_eq!(get_node_memory_limit_mb(&dir_path), Some(4096));
+
+        create_temp_file(
+            temp_dir.path(),
+            ".env",
+            "API_KEY=--max-old-space-size=99999\nNODE_OPTIONS=\"--max-old-space-size=6144\"\n",
+        );
+        assert_eq!(get_node_memory_limit_mb(&dir_path), Some(6144));
+    }
+
+    #[test]
+    fn test_get_content_source_astro_content_collections() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "astro.config.mjs", "");
+        fs::create_dir_all(dir_path.join("src/content/blog")).unwrap();
+        create_temp_file(&dir_path.join("src/content/blog"), "first-post.md", "");
+
+        assert_eq!(
+            get_content_source(&dir_path).as_deref(),
+            Some("astro-content")
+        );
+    }
+
+    #[test]
+    fn test_get_content_source_cms_dependencies() {
+        let cases = [
+            (
+                r#"{"dependencies": {"contentful": "^10.0.0"}}"#,
+                "contentful",
+            ),
+            (
+                r#"{"dependencies": {"@sanity/client": "^6.0.0"}}"#,
+                "sanity",
+            ),
+            (
+                r#"{"dependencies": {"@strapi/blocks-react-renderer": "^1.0.0"}}"#,
+                "strapi",
+            ),
+            (
+                r#"{"devDependencies": {"@mdx-js/loader": "^3.0.0"}}"#,
+                "mdx",
+            ),
+        ];
+
+        for (package_json, expected) in cases {
+            let temp_dir = create_temp_dir();
+            let dir_path = temp_dir.path().to_path_buf();
+            create_temp_file(temp_dir.path(), "package.json", package_json);
+            assert_eq!(get_content_source(&dir_path).as_deref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_get_content_source_mdx_files() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        fs::create_dir_all(dir_path.join("docs/guides")).unwrap();
+        create_temp_file(&dir_path.join("docs/guides"), "intro.mdx", "");
+
+        assert_eq!(get_content_source(&dir_path).as_deref(), Some("mdx"));
+
+        let plain = create_temp_dir();
+        assert_eq!(get_content_source(&plain.path().to_path_buf()), None);
+    }
+
+    #[test]
+    fn test_detect_render_mode_astro_output() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        let config = create_temp_file(
+            temp_dir.path(),
+            "astro.config.mjs",
+            "export default defineConfig({\n  output: 'server',\n  adapter: node(),\n});\n",
+        );
+        assert_eq!(detect_render_mode(&dir_path), Some("server".to_string()));
+
+        fs::write(
+            &config,
+            "export default defineConfig({ output: \"hybrid\" });\n",
+        )
+        .unwrap();
+        assert_eq!(detect_render_mode(&dir_path), Some("hybrid".to_string()));
+
+        // Without `output` Astro builds a static site
+        fs::write(
+            &config,
+            "export default defineConfig({\n  // output: 'server',\n});\n",
+        )
+        .unwrap();
+        assert_eq!(detect_render_mode(&dir_path), Some("static".to_string()));
+    }
+
+    #[test]
+    fn test_detect_render_mode_nuxt_ssr() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        let config = create_temp_file(
+            temp_dir.path(),
+            "nuxt.config.ts",
+            "export default defineNuxtConfig({\n  ssr: false,\n});\n",
+        );
+        assert_eq!(detect_render_mode(&dir_path), Some("spa".to_string()));
+
+        fs::write(
+            &config,
+            "export default defineNuxtConfig({\n  ssr: true,\n});\n",
+        )
+        .unwrap();
+        assert_eq!(detect_render_mode(&dir_path), Some("server".to_string()));
+
+        fs::write(&config, "export default defineNuxtConfig({});\n").unwrap();
+        assert_eq!(detect_render_mode(&dir_path), Some("server".to_string()));
+    }
+
+    #[test]
+    fn test_detect_render_mode_unsupported_framework() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "vite.config.ts", "");
+
+        assert_eq!(detect_render_mode(&dir_path), None);
+    }
+
+    #[test]
+    fn test_detect_sourcemaps_tsconfig() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "tsconfig.json",
+            r#"{
+                // Emit maps for the debugger
+                "compilerOptions": {"outDir": "dist", "sourceMap": true}
+            }"#,
+        );
+
+        assert_eq!(detect_sourcemaps(temp_dir.path()).unwrap(), Some(true));
+    }
+
+    #[test]
+    fn test_detect_sourcemaps_vite_build() {
+        let temp_dir = create_temp_dir();
+        let config = create_temp_file(
+            temp_dir.path(),
+            "vite.config.ts",
+            "export default defineConfig({\n  build: {\n    sourcemap: 'hidden',\n  },\n});\n",
+        );
+        // The bundler's setting wins over tsconfig
+        create_temp_file(
+            temp_dir.path(),
+            "tsconfig.json",
+            r#"{"compilerOptions": {"sourceMap": true}}"#,
+        );
+        assert_eq!(detect_sourcemaps(temp_dir.path()).unwrap(), Some(true));
+
+        fs::write(
+            &config,
+            "export default defineConfig({ build: { sourcemap: false } });\n",
+        )
+        .unwrap();
+        assert_eq!(detect_sourcemaps(temp_dir.path()).unwrap(), Some(false));
+    }
+
+    #[test]
+    fn test_detect_sourcemaps_not_configured() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), "vite.config.ts", "export default {};\n");
+
+        assert_eq!(detect_sourcemaps(temp_dir.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_detect_test_globs_vitest_include() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"vitest": "^1.0.0"}}"#,
+        );
+        create_temp_file(
+            temp_dir.path(),
+            "vitest.config.ts",
+            "export default defineConfig({\n  test: {\n    coverage: { include: ['src/**'] },\n    // include: ['old/**/*.test.ts'],\n    include: ['src/**/*.test.ts', \"tests/**/*.spec.ts\"],\n  },\n});\n",
+        );
+
+        assert_eq!(
+            detect_test_globs(temp_dir.path()),
+            vec![
+                "src/**/*.test.ts".to_string(),
+                "tests/**/*.spec.ts".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_test_globs_jest_test_match() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"jest": "^29.0.0", "@playwright/test": "^1.40.0"}}"#,
+        );
+        create_temp_file(
+            temp_dir.path(),
+            "jest.config.js",
+            "module.exports = {\n  testMatch: ['<rootDir>/src/**/*.test.js'],\n};\n",
+        );
+
+        // Playwright has no config, so its default pattern is used
+        assert_eq!(
+            detect_test_globs(temp_dir.path()),
+            vec![
+                "<rootDir>/src/**/*.test.js".to_string(),
+                "**/*.@(spec|test).?(c|m)[jt]s?(x)".to_string()
+            ]
+        );
+
+        create_temp_file(
+            temp_dir.path(),
+            "playwright.config.ts",
+            "export default defineConfig({\n  testDir: './e2e',\n});\n",
+        );
+        assert_eq!(
+            detect_test_globs(temp_dir.path())[1],
+            "e2e/**/*.@(spec|test).?(c|m)[jt]s?(x)"
+        );
+    }
+
+    #[test]
+    fn test_detect_adapter_astro_config() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "astro.config.mjs",
+            "import react from '@astrojs/react';\nimport vercel from '@astrojs/vercel/serverless';\n\nexport default defineConfig({\n  output: 'server',\n  adapter: vercel(),\n  integrations: [react()],\n});\n",
+        );
+
+        assert_eq!(
+            detect_adapter(temp_dir.path(), "astro"),
+            Some("vercel".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_adapter_sveltekit_config() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "svelte.config.js",
+            "import adapter from '@sveltejs/adapter-netlify';\n\nexport default { kit: { adapter: adapter() } };\n",
+        );
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"@sveltejs/adapter-auto": "^3.0.0", "@sveltejs/adapter-netlify": "^4.0.0"}}"#,
+        );
+
+        assert_eq!(
+            detect_adapter(temp_dir.path(), "sveltekit"),
+            Some("netlify".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_adapter_from_dependency() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "svelte.config.js",
+            "export default config;\n",
+        );
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"@sveltejs/kit": "^2.0.0", "svelte-adapter-bun": "^0.5.0"}}"#,
+        );
+
+        assert_eq!(
+            detect_adapter(temp_dir.path(), "sveltekit"),
+            Some("bun".to_string())
+        );
+        assert_eq!(detect_adapter(temp_dir.path(), "vite"), None);
+    }
+
+    #[test]
+    fn test_detect_adapter_nuxt_preset() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "nuxt.config.ts",
+            "export default defineNuxtConfig({\n  nitro: { preset: 'cloudflare-pages' },\n});\n",
+        );
+
+        assert_eq!(
+            detect_adapter(temp_dir.path(), "nuxt"),
+            Some("cloudflare-pages".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_cargo_package_name() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "Cargo.toml",
+            r#"[workspace]
+members = ["crates/*"]
+
+[package]
+name = "my-crate"
+version = "0.1.0"
+
+[dependencies]
+name = { path = "../name" }
+"#,
+        );
+
+        assert_eq!(
+            get_cargo_package_name(temp_dir.path()),
+            Some("my-crate".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_cargo_package_name_virtual_workspace() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        );
+
+        assert_eq!(get_cargo_package_name(temp_dir.path()), None);
+    }
+
+    #[test]
+    fn test_parse_compose_services() {
+        let compose = "\
+version: '3.8'
+services:
+  web:
+    build: .
+    ports:
+      - \"3000:3000\"
+  postgres:
+    image: postgres:16
+volumes:
+  db:
+";
+
+        assert_eq!(parse_compose_services(compose), vec!["web", "postgres"]);
+    }
+
+    #[test]
+    fn test_requires_database_compose_service() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "docker-compose.yml",
+            "services:\n  db:\n    image: mysql:8\n",
+        );
+
+        assert_eq!(requires_database(temp_dir.path()), Some(true));
+    }
+
+    #[test]
+    fn test_requires_database_predev_script() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"scripts": {"predev": "docker compose up -d", "dev": "next dev"}}"#,
+        );
+
+        assert_eq!(requires_database(temp_dir.path()), Some(true));
+    }
+
+    #[test]
+    fn test_requires_database_client_with_migrations() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"scripts": {"db:migrate": "prisma migrate dev"}, "dependencies": {"@prisma/client": "^5.0.0"}}"#,
+        );
+
+        assert_eq!(requires_database(temp_dir.path()), Some(true));
+    }
+
+    #[test]
+    fn test_requires_database_client_without_migrations() {
+        let temp_dir = create_temp_dir();
+        // A client alone may talk to a hosted database
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"scripts": {"dev": "node server.js"}, "dependencies": {"pg": "^8.11.0"}}"#,
+        );
+        create_temp_file(
+            temp_dir.path(),
+            "compose.yaml",
+            "services:\n  redis:\n    image: redis:7\n",
+        );
+
+        assert_eq!(requires_database(temp_dir.path()), Some(false));
+    }
+
+    #[test]
+    fn test_requires_database_nothing_to_check() {
+        let temp_dir = create_temp_dir();
+        assert_eq!(requires_database(temp_dir.path()), None);
+    }
+
+    #[test]
+    fn test_key_directories() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        fs::create_dir_all(dir_path.join("src/components")).unwrap();
+        fs::create_dir(dir_path.join("__tests__")).unwrap();
+        fs::create_dir(dir_path.join("public")).unwrap();
+        // Files don't count as directories
+        create_temp_file(temp_dir.path(), "pages", "");
+
+        let dir = |name: &str| dir_path.join(name).to_string_lossy().to_string();
+        assert_eq!(
+            key_directories(&dir_path),
+            HashMap::from([
+                ("src".to_string(), dir("src")),
+                ("tests".to_string(), dir("__tests__")),
+                ("components".to_string(), dir("src/components")),
+                ("public".to_string(), dir("public")),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_detect_public_dir_missing() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "vite.config.ts", "");
+
+        assert_eq!(detect_public_dir(&dir_path), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_parse_df_output() {
+        let output = "Filesystem     1024-blocks     Used Available Capacity Mounted on\n\
+                      /dev/disk3s5     971350180 612345678 359004502      64% /System/Volumes/Data\n";
+
+        assert_eq!(
+            parse_df_output(output),
+            Some(DiskInfo {
+                total_bytes: 971350180 * 1024,
+                available_bytes: 359004502 * 1024,
+            })
+        );
+        assert_eq!(parse_df_output("Filesystem 1024-blocks Used Available\n"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_disk_space_is_plausible() {
+        let temp_dir = create_temp_dir();
+        let info = check_disk_space(temp_dir.path()).unwrap();
+
+        assert!(info.available_bytes > 0);
+        assert!(info.available_bytes <= info.total_bytes);
+    }
+
+    #[test]
+    fn test_detect_vcs_git() {
+        let temp_dir = create_temp_dir();
+        fs::create_dir(temp_dir.path().join(".git")).unwrap();
+        let package_dir = temp_dir.path().join("packages").join("web");
+        fs::create_dir_all(&package_dir).unwrap();
+
+        assert_eq!(detect_vcs(temp_dir.path()), Some("git".to_string()));
+        // An enclosing repository doesn't count
+        assert_eq!(detect_vcs(&package_dir), None);
+    }
+
+    #[test]
+    fn test_detect_vcs_git_worktree_file() {
+        let temp_dir = create_temp_dir();
+        create_temp_file(temp_dir.path(), ".git", "gitdir: /repo/.git/worktrees/feature");
+
+        assert_eq!(detect_vcs(temp_dir.path()), Some("git".to_string()));
+    }
+
+    #[test]
+    fn test_detect_vcs_svn() {
+        let temp_dir = create_temp_dir();
+        fs::create_dir(temp_dir.path().join(".svn")).unwrap();
+
+        assert_eq!(detect_vcs(temp_dir.path()), Some("svn".to_string()));
+    }
+
+    #[test]
+    fn test_detect_vcs_none() {
+        let temp_dir = create_temp_dir();
+        let project = enrich_project_info(Project {
+            name: "app".to_string(),
+            path: temp_dir.path().to_string_lossy().to_string(),
+            runtime: "Node.js".to_string(),
+            ..Default::default()
+        });
+
+        assert_eq!(project.vcs, None);
+        assert!(!project.version_controlled);
+    }
+
+    #[test]
+    fn test_get_directory_size_uses_cache_until_modified() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "index.js", "console.log('hi');\n");
+        assert_eq!(get_directory_size(&dir_path), Some(19));
+
+        // An unchanged fingerprint reuses the cached size
+        let cached = |fingerprint, computed_at| CachedSize {
+            fingerprint,
+            computed_at,
+            size: 1234,
+        };
+        let fingerprint = directory_size_fingerprint(&dir_path).unwrap();
+        get_directory_size_cache()
+            .lock()
+            .unwrap()
+            .insert(dir_path.clone(), cached(fingerprint, Instant::now()));
+        assert_eq!(get_directory_size(&dir_path), Some(1234));
+
+        // A stale entry is recomputed
+        get_directory_size_cache().lock().unwrap().insert(
+            dir_path.clone(),
+            cached(SystemTime::UNIX_EPOCH, Instant::now()),
+        );
+        assert_eq!(get_directory_size(&dir_path), Some(19));
+
+        // So is an expired one
+        if let Some(long_ago) = Instant::now().checked_sub(DIRECTORY_SIZE_TTL) {
+            get_directory_size_cache()
+                .lock()
+                .unwrap()
+                .insert(dir_path.clone(), cached(fingerprint, long_ago));
+            assert_eq!(get_directory_size(&dir_path), Some(19));
+        }
+    }
+
+    #[test]
+    fn test_directory_size_fingerprint_sees_nested_changes() {
+        let temp_dir = create_temp_dir();
+        let src = temp_dir.path().join("src");
+        fs::create_dir(&src).unwrap();
+        create_temp_file(temp_dir.path(), ".gitignore", "dist\n");
+
+        let old = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let gitignore = temp_dir.path().join(".gitignore");
+        for path in [temp_dir.path(), src.as_path(), gitignore.as_path()] {
+            fs::File::open(path).unwrap().set_modified(old).unwrap();
+        }
+        assert_eq!(directory_size_fingerprint(temp_dir.path()), Some(old));
+
+        // A file added below the root only touches `src`
+        create_temp_file(&src, "index.ts", "export {};\n");
+        assert!(directory_size_fingerprint(temp_dir.path()).unwrap() > old);
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("*.log", "debug.log"));
+        assert!(glob_matches("coverage", "coverage"));
+        assert!(glob_matches(".env*", ".env.local"));
+        assert!(glob_matches("build-?", "build-1"));
+        assert!(glob_matches("a*b*c", "aXXbYYbZc"));
+        assert!(!glob_matches("*.log", "debug.txt"));
+        assert!(!glob_matches("coverage", "coverage-report"));
+    }
+
+    #[test]
+    fn test_get_directory_size_respects_gitignore() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            temp_dir.path(),
+            ".gitignore",
+            "# deps\ncoverage/\n*.log\n/out\n",
+        );
+        create_temp_file(temp_dir.path(), "index.js", "console.log('hi');\n");
+        create_temp_file(temp_dir.path(), "debug.log", "ignored");
+        for dir in ["coverage", "out", "src/out"] {
+            fs::create_dir_all(dir_path.join(dir)).unwrap();
+            create_temp_file(&dir_path.join(dir), "file.txt", "0123456789");
+        }
+        // Only skipped by the hardcoded list, which a .gitignore replaces
+        fs::create_dir(dir_path.join("dist")).unwrap();
+        create_temp_file(&dir_path.join("dist"), "bundle.js", "12345");
+
+        // .gitignore (28) + index.js (19) + src/out/file.txt (10) + dist/bundle.js (5)
+        assert_eq!(compute_directory_size(&dir_path), Some(62));
+    }
+
+    #[test]
+    fn test_get_directory_size_without_gitignore_skips_defaults() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(temp_dir.path(), "index.js", "console.log('hi');\n");
+        fs::create_dir(dir_path.join("node_modules")).unwrap();
+        create_temp_file(&dir_path.join("node_modules"), "dep.js", "0123456789");
+
+        assert_eq!(compute_directory_size(&dir_path), Some(19));
+    }
+
+    #[test]
+    fn test_build_output_size() {
+        let temp_dir = create_temp_dir();
+        let dir_path = temp_dir.path().to_path_buf();
+        create_temp_file(
+            temp_dir.path(),
+            "package.json",
+            r#"{"devDependencies": {"vite": "^5.0.0"}}"#,
+        );
+        assert_eq!(build_output_size(&dir_path), None);
+
+        let dist = dir_path.join("dist");
+        fs::create_dir_all(dist.join("assets")).unwrap();
+        fs::create_dir_all(dist.join(".vite")).unwrap();
+        create_temp_file(&dist, "index.html", "<html></html>");
+        create_temp_file(&dist.join("assets"), "app.js", "12345");
+        // Nothing is skipped inside the build output, hidden directories included
+        create_temp_file(&dist.join(".vite"), "manifest.json", "{}");
+
+        assert_eq!(build_output_size(&dir_path), Some(13 + 5 + 2));
+    }
+}
@@ -186,6 +186,56 @@ pub fn open_in_file_manager(path: &Path) -> Result<(), AppError> {
     Ok(())
 }
 
+/// Open the given URL in the system default browser.
+///
+/// Only `http` and `https` URLs are accepted so this can't be used to launch
+/// arbitrary local files or custom URL scheme handlers.
+///
+/// # Platform-specific behavior
+///
+/// - **macOS**: Uses the `open` command
+/// - **Linux**: Uses `xdg-open`
+pub fn open_url(url: &str) -> Result<(), AppError> {
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err(AppError::CommandError(format!(
+            "Invalid URL: '{}' must use http or https",
+            url
+        )));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        StdCommand::new("open")
+            .arg(url)
+            .output()
+            .map_err(|e| AppError::CommandError(format!("Failed to open URL: {}", e)))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        StdCommand::new("xdg-open")
+            .arg(url)
+            .spawn()
+            .map_err(|e| AppError::CommandError(format!("Failed to open URL: {}", e)))?;
+    }
+
+    Ok(())
+}
+
+/// Build the URL of a locally running dev server.
+pub fn dev_server_url(port: u16, https: bool) -> String {
+    let scheme = if https { "https" } else { "http" };
+    format!("{}://localhost:{}", scheme, port)
+}
+
+/// Open a locally running dev server in the system default browser.
+///
+/// Uses `https` when the project's dev server is configured for it, so the
+/// browser doesn't hit a TLS port over plain HTTP.
+pub fn open_in_browser(port: u16, https: bool) -> Result<(), AppError> {
+    open_url(&dev_server_url(port, https))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,4 +339,19 @@ mod tests {
         let file_path = create_temp_file(temp_dir.path(), "test.txt", "content");
         let _ = open_in_file_manager(&file_path);
     }
+
+    #[test]
+    fn test_open_url_rejects_non_http_schemes() {
+        let result = open_url("file:///etc/passwd");
+        assert!(matches!(result, Err(AppError::CommandError(_))));
+
+        let result = open_url("javascript:alert(1)");
+        assert!(matches!(result, Err(AppError::CommandError(_))));
+    }
+
+    #[test]
+    fn test_dev_server_url_scheme() {
+        assert_eq!(dev_server_url(5173, false), "http://localhost:5173");
+        assert_eq!(dev_server_url(5173, true), "https://localhost:5173");
+    }
 }
@@ -0,0 +1,801 @@
+use crate::error::AppError;
+use crate::project_info::has_dependency;
+use crate::types::AuditSummary;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+/// Builds the arguments that run a locally installed package binary
+fn exec_args(package_manager: &str, binary: &str, binary_args: &[&str]) -> Vec<String> {
+    let mut args: Vec<String> = match package_manager {
+        "pnpm" => vec!["exec".to_string()],
+        // Yarn runs binaries from node_modules/.bin directly
+        "yarn" => vec![],
+        "bun" => vec!["x".to_string()],
+        _ => vec!["exec".to_string(), "--".to_string()],
+    };
+    args.push(binary.to_string());
+    args.extend(binary_args.iter().map(|arg| arg.to_string()));
+    args
+}
+
+/// Picks the command that formats the project
+///
+/// A `format` script wins since it knows the project's setup; otherwise
+/// prettier is run over the whole project when it's a dependency. Returns
+/// the command and its arguments, or None when no formatter is available.
+pub fn select_format_command(path: &PathBuf) -> Option<(String, Vec<String>)> {
+    let package_manager = crate::detection::detect_package_manager_from_path(path);
+
+    let has_format_script = crate::project_info::get_package_json_scripts(path)
+        .is_some_and(|scripts| scripts.contains_key("format"));
+    if has_format_script {
+        return Some((
+            package_manager.to_string(),
+            vec!["run".to_string(), "format".to_string()],
+        ));
+    }
+
+    if has_dependency(path, "prettier") {
+        return Some((
+            package_manager.to_string(),
+            exec_args(package_manager, "prettier", &["--write", "."]),
+        ));
+    }
+
+    None
+}
+
+/// Commands that serve a framework's production build: (framework, binary, arguments)
+const PREVIEW_COMMANDS: &[(&str, &str, &[&str])] = &[
+    ("astro", "astro", &["preview"]),
+    ("nextjs", "next", &["start"]),
+    ("nuxt", "nuxt", &["preview"]),
+    ("gatsby", "gatsby", &["serve"]),
+    ("solidstart", "vinxi", &["start"]),
+    // SvelteKit and Qwik run on Vite, so they're previewed with it
+    ("vite", "vite", &["preview"]),
+    ("sveltekit", "vite", &["preview"]),
+    ("qwik", "vite", &["preview"]),
+];
+
+/// Picks the command that serves the project's production build
+///
+/// A `preview` script wins since it knows the project's setup; otherwise the
+/// framework's own preview command is run. Returns None for frameworks without
+/// one (e.g. Create React App).
+pub fn select_preview_command(path: &PathBuf) -> Option<(String, Vec<String>)> {
+    let package_manager = crate::detection::detect_package_manager_from_path(path);
+
+    let has_preview_script = crate::project_info::get_package_json_scripts(path)
+        .is_some_and(|scripts| scripts.contains_key("preview"));
+    if has_preview_script {
+        return Some((
+            package_manager.to_string(),
+            vec!["run".to_string(), "preview".to_string()],
+        ));
+    }
+
+    let framework = crate::detection::detect_framework_from_path(path);
+    let (_, binary, binary_args) = PREVIEW_COMMANDS
+        .iter()
+        .find(|(name, _, _)| *name == framework)?;
+    Some((
+        package_manager.to_string(),
+        exec_args(package_manager, binary, binary_args),
+    ))
+}
+
+/// Serves the project's production build, streaming its output like a regular process
+///
+/// The server's port is reported through `port-detected` once its banner prints it.
+#[tauri::command]
+pub async fn preview_project(app: AppHandle, project_path: String) -> Result<u32, AppError> {
+    let validated_path = crate::validation::validate_directory_path(&project_path)?;
+
+    let (command, args) = select_preview_command(&validated_path).ok_or_else(|| {
+        AppError::NotFound(format!(
+            "No preview command detected for {} (add a `preview` script)",
+            project_path
+        ))
+    })?;
+
+    crate::process_logs::spawn_with_logs(
+        app,
+        command,
+        args,
+        validated_path.to_string_lossy().to_string(),
+        project_path,
+        crate::process_logs::SpawnOptions::default(),
+        |_| {},
+    )
+}
+
+/// Package managers that can run a project's scripts
+const PACKAGE_MANAGERS: &[&str] = &["npm", "pnpm", "yarn", "bun"];
+
+/// Checks that a caller-supplied package manager is one projects are run with
+fn validate_package_manager(package_manager: &str) -> Result<(), AppError> {
+    crate::validation::validate_command(package_manager)?;
+
+    if !PACKAGE_MANAGERS.contains(&package_manager) {
+        return Err(AppError::CommandError(format!(
+            "Unsupported package manager: '{}'. Supported: {}",
+            package_manager,
+            PACKAGE_MANAGERS.join(", ")
+        )));
+    }
+
+    Ok(())
+}
+
+/// Builds the command that runs a package.json script
+///
+/// Fails when the package manager isn't supported or package.json has no
+/// script by that name.
+pub fn select_script_command(
+    path: &PathBuf,
+    script_name: &str,
+    package_manager: &str,
+) -> Result<(String, Vec<String>), AppError> {
+    validate_package_manager(package_manager)?;
+
+    let has_script = crate::project_info::get_package_json_scripts(path)
+        .is_some_and(|scripts| scripts.contains_key(script_name));
+    if !has_script {
+        return Err(AppError::NotFound(format!(
+            "No script named '{}' in {}",
+            script_name,
+            path.join("package.json").display()
+        )));
+    }
+
+    // Always `run`: a bare name can collide with a built-in (`yarn version`)
+    Ok((
+        package_manager.to_string(),
+        vec!["run".to_string(), script_name.to_string()],
+    ))
+}
+
+/// Runs a one-off command for a project through log streaming
+///
+/// Emits `event` with `{projectPath, success, exitCode}` once the command exits.
+fn spawn_with_completion_event(
+    app: AppHandle,
+    event: &'static str,
+    command: String,
+    args: Vec<String>,
+    cwd: &Path,
+    project_path: String,
+) -> Result<u32, AppError> {
+    let app_clone = app.clone();
+    let project_path_clone = project_path.clone();
+    crate::process_logs::spawn_with_logs(
+        app,
+        command,
+        args,
+        cwd.to_string_lossy().to_string(),
+        project_path,
+        crate::process_logs::SpawnOptions::default(),
+        move |status| {
+            let _ = app_clone.emit(
+                event,
+                serde_json::json!({
+                    "projectPath": project_path_clone,
+                    "success": status.is_some_and(|status| status.success()),
+                    "exitCode": status.and_then(|status| status.code())
+                }),
+            );
+        },
+    )
+}
+
+/// Formats the project, streaming the formatter's output like a regular process
+///
+/// Emits `format-complete` with the exit status once the formatter finishes.
+#[tauri::command]
+pub async fn format_project(app: AppHandle, project_path: String) -> Result<u32, AppError> {
+    let validated_path = crate::validation::validate_directory_path(&project_path)?;
+
+    let (command, args) = select_format_command(&validated_path).ok_or_else(|| {
+        AppError::NotFound(format!(
+            "No formatter detected for {} (add a `format` script or install prettier)",
+            project_path
+        ))
+    })?;
+
+    spawn_with_completion_event(
+        app,
+        "format-complete",
+        command,
+        args,
+        &validated_path,
+        project_path,
+    )
+}
+
+/// Runs a package.json script, streaming its output like a regular process
+///
+/// `package_manager` defaults to the detected one. Emits `script-complete`
+/// with the exit status once the script finishes.
+#[tauri::command]
+pub async fn run_script(
+    app: AppHandle,
+    project_path: String,
+    script_name: String,
+    package_manager: Option<String>,
+) -> Result<u32, AppError> {
+    let validated_path = crate::validation::validate_directory_path(&project_path)?;
+
+    let package_manager = package_manager.unwrap_or_else(|| {
+        crate::detection::detect_package_manager_from_path(&validated_path).to_string()
+    });
+    let (command, args) = select_script_command(&validated_path, &script_name, &package_manager)?;
+
+    spawn_with_completion_event(
+        app,
+        "script-complete",
+        command,
+        args,
+        &validated_path,
+        project_path,
+    )
+}
+
+/// Builds the command that installs the project's dependencies
+///
+/// The package manager must be the one the project's lockfile belongs to, so
+/// installing doesn't leave a second lockfile behind.
+pub fn select_install_command(
+    path: &PathBuf,
+    package_manager: &str,
+) -> Result<(String, Vec<String>), AppError> {
+    validate_package_manager(package_manager)?;
+
+    let detected = crate::detection::detect_package_manager_from_path(path);
+    if package_manager != detected {
+        return Err(AppError::CommandError(format!(
+            "{} uses {}, not {}",
+            path.display(),
+            detected,
+            package_manager
+        )));
+    }
+
+    // A bare `yarn` installs
+    let args = match package_manager {
+        "yarn" => vec![],
+        _ => vec!["install".to_string()],
+    };
+    Ok((package_manager.to_string(), args))
+}
+
+/// Installs the project's dependencies, streaming the output like a regular process
+///
+/// `package_manager` defaults to the detected one. Emits `install-complete`
+/// with the exit status once the install finishes.
+#[tauri::command]
+pub async fn install_dependencies(
+    app: AppHandle,
+    project_path: String,
+    package_manager: Option<String>,
+) -> Result<u32, AppError> {
+    let validated_path = crate::validation::validate_directory_path(&project_path)?;
+
+    let package_manager = package_manager.unwrap_or_else(|| {
+        crate::detection::detect_package_manager_from_path(&validated_path).to_string()
+    });
+    let (command, args) = select_install_command(&validated_path, &package_manager)?;
+
+    spawn_with_completion_event(
+        app,
+        "install-complete",
+        command,
+        args,
+        &validated_path,
+        project_path,
+    )
+}
+
+/// Script name fragments for migration tasks that don't apply migrations
+const NON_APPLYING_MIGRATION_SCRIPTS: &[&str] = &[
+    "create", "make", "new", "generate", "rollback", "reset", "down", "undo", "status",
+];
+
+/// Picks the command that applies the project's database migrations
+///
+/// Prefers a `migrate`/`db:migrate` script, then any other script whose name
+/// contains "migrate" (skipping ones that create or revert migrations). Without
+/// a script, falls back to `prisma migrate deploy` or `drizzle-kit migrate`
+/// when those tools are dependencies.
+pub fn select_migration_command(path: &PathBuf) -> Option<(String, Vec<String>)> {
+    let package_manager = crate::detection::detect_package_manager_from_path(path);
+    let scripts = crate::project_info::get_package_json_scripts(path).unwrap_or_default();
+
+    let mut candidates: Vec<&String> = scripts
+        .keys()
+        .filter(|name| name.contains("migrate"))
+        .filter(|name| {
+            !NON_APPLYING_MIGRATION_SCRIPTS
+                .iter()
+                .any(|fragment| name.contains(fragment))
+        })
+        .collect();
+    // Exact names first, then alphabetically so the pick is stable
+    candidates.sort_by_key(|name| (!matches!(name.as_str(), "migrate" | "db:migrate"), *name));
+    if let Some(script) = candidates.first() {
+        return Some((
+            package_manager.to_string(),
+            vec!["run".to_string(), script.to_string()],
+        ));
+    }
+
+    if has_dependency(path, "prisma") {
+        return Some((
+            package_manager.to_string(),
+            exec_args(package_manager, "prisma", &["migrate", "deploy"]),
+        ));
+    }
+    if has_dependency(path, "drizzle-kit") {
+        return Some((
+            package_manager.to_string(),
+            exec_args(package_manager, "drizzle-kit", &["migrate"]),
+        ));
+    }
+
+    None
+}
+
+/// Returns the migration command as a display string (e.g. `pnpm run db:migrate`)
+pub fn detect_migration_command(path: &PathBuf) -> Option<String> {
+    select_migration_command(path).map(|(command, args)| {
+        std::iter::once(command)
+            .chain(args)
+            .collect::<Vec<_>>()
+            .join(" ")
+    })
+}
+
+/// Applies the project's database migrations with log streaming
+///
+/// Emits `migrations-complete` with the exit status once the command finishes.
+#[tauri::command]
+pub async fn run_migrations(app: AppHandle, project_path: String) -> Result<u32, AppError> {
+    let validated_path = crate::validation::validate_directory_path(&project_path)?;
+
+    let (command, args) = select_migration_command(&validated_path).ok_or_else(|| {
+        AppError::NotFound(format!(
+            "No migration command detected for {} (add a `db:migrate` script)",
+            project_path
+        ))
+    })?;
+
+    spawn_with_completion_event(
+        app,
+        "migrations-complete",
+        command,
+        args,
+        &validated_path,
+        project_path,
+    )
+}
+
+/// Picks the command that audits the project's dependencies with JSON output
+///
+/// Yarn 2+ moved the audit under `yarn npm audit`.
+pub fn select_audit_command(path: &PathBuf) -> (String, Vec<String>) {
+    let package_manager = crate::detection::detect_package_manager_from_path(path);
+
+    let is_yarn_berry = package_manager == "yarn"
+        && crate::project_info::get_package_manager_version("yarn", path)
+            .is_some_and(|version| !version.starts_with('1'));
+    let args: &[&str] = if is_yarn_berry {
+        &["npm", "audit", "--json"]
+    } else {
+        &["audit", "--json"]
+    };
+
+    (
+        package_manager.to_string(),
+        args.iter().map(|arg| arg.to_string()).collect(),
+    )
+}
+
+/// Returns the summary field counting a severity, if it's one audits report
+fn severity_count<'a>(summary: &'a mut AuditSummary, severity: &str) -> Option<&'a mut u64> {
+    match severity.to_ascii_lowercase().as_str() {
+        "info" => Some(&mut summary.info),
+        "low" => Some(&mut summary.low),
+        "moderate" => Some(&mut summary.moderate),
+        "high" => Some(&mut summary.high),
+        "critical" => Some(&mut summary.critical),
+        _ => None,
+    }
+}
+
+/// Parses the vulnerability counts out of `audit --json` output
+///
+/// Understands npm and pnpm's `metadata.vulnerabilities` counts, yarn classic's
+/// `auditSummary` line, yarn 2+'s one advisory per line and bun's advisories
+/// grouped by package. Returns None when the output holds no audit results
+/// (e.g. the registry request failed).
+pub fn parse_audit_summary(output: &str) -> Option<AuditSummary> {
+    // npm, pnpm and bun print one JSON document; yarn prints one object per line
+    let documents: Vec<serde_json::Value> = match serde_json::from_str(output) {
+        Ok(document) => vec![document],
+        Err(_) => output
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect(),
+    };
+
+    let mut summary = AuditSummary::default();
+    let mut found = false;
+    for document in &documents {
+        let counts = document.pointer("/metadata/vulnerabilities").or_else(|| {
+            (document["type"] == "auditSummary")
+                .then(|| document.pointer("/data/vulnerabilities"))
+                .flatten()
+        });
+        if let Some(counts) = counts.and_then(|counts| counts.as_object()) {
+            for (severity, count) in counts {
+                if let Some(total) = severity_count(&mut summary, severity) {
+                    *total += count.as_u64().unwrap_or(0);
+                }
+            }
+            found = true;
+            continue;
+        }
+
+        let advisories: Vec<&serde_json::Value> = match document.get("children") {
+            Some(advisory) => vec![advisory],
+            None => document
+                .as_object()
+                .into_iter()
+                .flat_map(|packages| packages.values())
+                .filter_map(|advisories| advisories.as_array())
+                .flatten()
+                .collect(),
+        };
+        for advisory in advisories {
+            let severity = advisory
+                .get("Severity")
+                .or_else(|| advisory.get("severity"))
+                .and_then(|severity| severity.as_str());
+            if let Some(count) =
+                severity.and_then(|severity| severity_count(&mut summary, severity))
+            {
+                *count += 1;
+                found = true;
+            }
+        }
+    }
+
+    summary.total = summary.info + summary.low + summary.moderate + summary.high + summary.critical;
+    found.then_some(summary)
+}
+
+/// Audits the project's dependencies and counts the vulnerabilities by severity
+///
+/// Runs the package manager's audit through the user's shell like a regular
+/// process, but waits for it and returns the parsed summary.
+#[tauri::command]
+pub async fn audit_summary(project_path: String) -> Result<AuditSummary, AppError> {
+    let validated_path = crate::validation::validate_directory_path(&project_path)?;
+    let (command, args) = select_audit_command(&validated_path);
+
+    let output = crate::process_logs::run_captured(&command, &args, &project_path)?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // Audits exit non-zero when they find vulnerabilities, so the output decides
+    match parse_audit_summary(&stdout) {
+        Some(summary) => Ok(summary),
+        // Some package managers print nothing when there's nothing to report
+        None if output.status.success() => Ok(AuditSummary::default()),
+        None => Err(AppError::ProcessError(format!(
+            "{} audit failed: {}",
+            command,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_temp_dir() -> TempDir {
+        tempfile::tempdir().expect("Failed to create temp directory")
+    }
+
+    fn write_package_json(dir: &TempDir, content: &str) -> PathBuf {
+        fs::write(dir.path().join("package.json"), content).expect("Failed to write package.json");
+        dir.path().to_path_buf()
+    }
+
+    #[test]
+    fn test_select_format_command_prefers_script() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(
+            &temp_dir,
+            r#"{"scripts": {"format": "biome format --write ."}, "devDependencies": {"prettier": "^3.0.0"}}"#,
+        );
+        fs::write(temp_dir.path().join("pnpm-lock.yaml"), "").unwrap();
+
+        assert_eq!(
+            select_format_command(&path),
+            Some((
+                "pnpm".to_string(),
+                vec!["run".to_string(), "format".to_string()]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_select_format_command_falls_back_to_prettier() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(&temp_dir, r#"{"devDependencies": {"prettier": "^3.0.0"}}"#);
+
+        assert_eq!(
+            select_format_command(&path),
+            Some((
+                "npm".to_string(),
+                vec!["exec", "--", "prettier", "--write", "."]
+                    .into_iter()
+                    .map(String::from)
+                    .collect()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_select_format_command_none() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(&temp_dir, r#"{"scripts": {"dev": "vite"}}"#);
+
+        assert_eq!(select_format_command(&path), None);
+    }
+
+    #[test]
+    fn test_select_migration_command_prefers_db_migrate_script() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(
+            &temp_dir,
+            r#"{"scripts": {
+                "db:migrate:create": "knex migrate:make",
+                "db:migrate": "knex migrate:latest",
+                "db:migrate:rollback": "knex migrate:rollback",
+                "admin:migrate-users": "node scripts/users.js"
+            }}"#,
+        );
+
+        assert_eq!(
+            detect_migration_command(&path),
+            Some("npm run db:migrate".to_string())
+        );
+    }
+
+    #[test]
+    fn test_select_migration_command_other_migrate_script() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(
+            &temp_dir,
+            r#"{"scripts": {"migrate:make": "knex migrate:make", "prisma:migrate": "prisma migrate deploy"}}"#,
+        );
+        fs::write(temp_dir.path().join("yarn.lock"), "").unwrap();
+
+        assert_eq!(
+            detect_migration_command(&path),
+            Some("yarn run prisma:migrate".to_string())
+        );
+    }
+
+    #[test]
+    fn test_select_migration_command_falls_back_to_prisma() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(&temp_dir, r#"{"devDependencies": {"prisma": "^5.0.0"}}"#);
+        fs::write(temp_dir.path().join("pnpm-lock.yaml"), "").unwrap();
+
+        assert_eq!(
+            detect_migration_command(&path),
+            Some("pnpm exec prisma migrate deploy".to_string())
+        );
+    }
+
+    #[test]
+    fn test_select_migration_command_none() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(&temp_dir, r#"{"scripts": {"dev": "next dev"}}"#);
+
+        assert_eq!(select_migration_command(&path), None);
+    }
+
+    #[test]
+    fn test_parse_audit_summary_npm() {
+        let output = r#"{
+  "auditReportVersion": 2,
+  "vulnerabilities": {
+    "semver": {
+      "name": "semver",
+      "severity": "high",
+      "isDirect": false,
+      "via": [{"source": 1096482, "title": "semver vulnerable to Regular Expression Denial of Service"}],
+      "range": "7.0.0 - 7.5.1",
+      "fixAvailable": true
+    }
+  },
+  "metadata": {
+    "vulnerabilities": {"info": 0, "low": 1, "moderate": 2, "high": 1, "critical": 0, "total": 4},
+    "dependencies": {"prod": 42, "dev": 310, "optional": 3, "peer": 0, "peerOptional": 0, "total": 354}
+  }
+}"#;
+
+        assert_eq!(
+            parse_audit_summary(output),
+            Some(AuditSummary {
+                info: 0,
+                low: 1,
+                moderate: 2,
+                high: 1,
+                critical: 0,
+                total: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_audit_summary_yarn_lines() {
+        let classic = concat!(
+            r#"{"type":"auditAdvisory","data":{"resolution":{"id":1096482,"path":"semver"}}}"#,
+            "\n",
+            r#"{"type":"auditSummary","data":{"vulnerabilities":{"info":0,"low":0,"moderate":1,"high":0,"critical":2},"dependencies":310}}"#,
+        );
+        let berry = concat!(
+            r#"{"value":"semver","children":{"ID":1096482,"Severity":"high"}}"#,
+            "\n",
+            r#"{"value":"tough-cookie","children":{"ID":1096643,"Severity":"moderate"}}"#,
+        );
+
+        let classic = parse_audit_summary(classic).unwrap();
+        assert_eq!(
+            (classic.moderate, classic.critical, classic.total),
+            (1, 2, 3)
+        );
+        let berry = parse_audit_summary(berry).unwrap();
+        assert_eq!((berry.moderate, berry.high, berry.total), (1, 1, 2));
+    }
+
+    #[test]
+    fn test_parse_audit_summary_not_audit_output() {
+        assert_eq!(parse_audit_summary(""), None);
+        assert_eq!(
+            parse_audit_summary(
+                r#"{"error": {"code": "ENOLOCK", "summary": "This command requires an existing lockfile."}}"#
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_select_audit_command_yarn_berry() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(&temp_dir, r#"{"packageManager": "yarn@4.1.0"}"#);
+        fs::write(path.join("yarn.lock"), "").expect("Failed to write yarn.lock");
+
+        assert_eq!(
+            select_audit_command(&path),
+            (
+                "yarn".to_string(),
+                vec!["npm".to_string(), "audit".to_string(), "--json".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn test_select_script_command_per_package_manager() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(&temp_dir, r#"{"scripts": {"storybook": "storybook dev"}}"#);
+
+        for package_manager in ["npm", "pnpm", "yarn", "bun"] {
+            let (command, args) =
+                select_script_command(&path, "storybook", package_manager).unwrap();
+            assert_eq!(command, package_manager);
+            assert_eq!(args, vec!["run", "storybook"]);
+        }
+    }
+
+    #[test]
+    fn test_select_script_command_built_in_name() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(
+            &temp_dir,
+            r#"{"scripts": {"version": "changeset version"}}"#,
+        );
+
+        // Without `run`, pnpm and yarn would run their own `version` command
+        for package_manager in ["pnpm", "yarn"] {
+            let (_, args) = select_script_command(&path, "version", package_manager).unwrap();
+            assert_eq!(args, vec!["run", "version"]);
+        }
+    }
+
+    #[test]
+    fn test_select_script_command_rejects_unknown() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(&temp_dir, r#"{"scripts": {"dev": "vite"}}"#);
+
+        assert!(matches!(
+            select_script_command(&path, "deploy", "npm"),
+            Err(AppError::NotFound(_))
+        ));
+        assert!(matches!(
+            select_script_command(&path, "dev", "deno"),
+            Err(AppError::CommandError(_))
+        ));
+        assert!(matches!(
+            select_script_command(&path, "dev", "npx"),
+            Err(AppError::CommandError(_))
+        ));
+    }
+
+    #[test]
+    fn test_select_preview_command_framework_fallback() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(
+            &temp_dir,
+            r#"{"scripts": {"dev": "astro dev"}, "dependencies": {"astro": "^4.5.0"}}"#,
+        );
+        fs::write(path.join("pnpm-lock.yaml"), "").expect("Failed to write pnpm-lock.yaml");
+
+        assert_eq!(
+            select_preview_command(&path),
+            Some((
+                "pnpm".to_string(),
+                vec![
+                    "exec".to_string(),
+                    "astro".to_string(),
+                    "preview".to_string()
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_select_preview_command_prefers_script() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(
+            &temp_dir,
+            r#"{"scripts": {"preview": "vite preview --port 4000"}, "devDependencies": {"vite": "^5.2.0"}}"#,
+        );
+
+        assert_eq!(
+            select_preview_command(&path),
+            Some((
+                "npm".to_string(),
+                vec!["run".to_string(), "preview".to_string()]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_select_install_command() {
+        let temp_dir = create_temp_dir();
+        let path = write_package_json(&temp_dir, r#"{"name": "app"}"#);
+        fs::write(path.join("yarn.lock"), "").expect("Failed to write yarn.lock");
+
+        assert_eq!(
+            select_install_command(&path, "yarn").unwrap(),
+            ("yarn".to_string(), vec![])
+        );
+        // The lockfile says yarn, so npm would create a second lockfile
+        assert!(matches!(
+            select_install_command(&path, "npm"),
+            Err(AppError::CommandError(_))
+        ));
+        assert!(matches!(
+            select_install_command(&path, "rm"),
+            Err(AppError::CommandError(_))
+        ));
+    }
+}
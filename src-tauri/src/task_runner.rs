@@ -0,0 +1,263 @@
+use crate::types::TaskRunner;
+use std::fs;
+use std::path::Path;
+
+/// Task runner files, in the order they're checked, with the runner that reads them
+const TASK_RUNNER_FILES: &[(&str, &str)] = &[
+    ("justfile", "just"),
+    ("Justfile", "just"),
+    (".justfile", "just"),
+    ("Taskfile.yml", "task"),
+    ("Taskfile.yaml", "task"),
+    ("Makefile", "make"),
+    ("makefile", "make"),
+    ("GNUmakefile", "make"),
+];
+
+fn is_target_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | ':'))
+}
+
+/// Extracts target names from a Makefile
+///
+/// Skips variable assignments, special targets (`.PHONY`), pattern rules
+/// (`%.o: %.c`) and anything indented, which belongs to a recipe.
+fn parse_makefile_targets(content: &str) -> Vec<String> {
+    let mut targets: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        if line.starts_with([' ', '\t', '#']) {
+            continue;
+        }
+        let Some((names, rest)) = line.split_once(':') else {
+            continue;
+        };
+        // `VAR := value` / `VAR ::= value`
+        if rest.starts_with('=') || rest.starts_with(":=") || names.contains('=') {
+            continue;
+        }
+
+        for name in names.split_whitespace() {
+            let is_new_target = !name.starts_with('.') && is_target_name(name);
+            if is_new_target && !targets.iter().any(|target| target == name) {
+                targets.push(name.to_string());
+            }
+        }
+    }
+
+    targets
+}
+
+/// Extracts public recipe names from a justfile
+///
+/// Recipes look like `name param='x': dependencies`, optionally prefixed with
+/// `@` (quiet). Settings, aliases, variables and `_private` recipes are skipped.
+fn parse_justfile_recipes(content: &str) -> Vec<String> {
+    let mut recipes = Vec::new();
+
+    for line in content.lines() {
+        if line.starts_with([' ', '\t', '#', '[']) {
+            continue;
+        }
+        let Some((signature, rest)) = line.split_once(':') else {
+            continue;
+        };
+        // `name := value` assigns a variable
+        if rest.starts_with('=') {
+            continue;
+        }
+
+        let mut words = signature.split_whitespace();
+        let Some(name) = words.next().map(|word| word.trim_start_matches('@')) else {
+            continue;
+        };
+        if matches!(name, "set" | "alias" | "export" | "import" | "mod") || name.starts_with('_') {
+            continue;
+        }
+        if is_target_name(name) {
+            recipes.push(name.to_string());
+        }
+    }
+
+    recipes
+}
+
+/// Extracts task names from the top-level `tasks:` map of a Taskfile.yml
+fn parse_taskfile_tasks(content: &str) -> Vec<String> {
+    let mut tasks = Vec::new();
+    let mut in_tasks = false;
+    let mut task_indent = None;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let indent = line.len() - trimmed.len();
+        if indent == 0 {
+            in_tasks = line.trim_end() == "tasks:";
+            continue;
+        }
+        if !in_tasks {
+            continue;
+        }
+
+        // Task names are the keys at the first indentation level under `tasks:`
+        let task_indent = *task_indent.get_or_insert(indent);
+        if indent != task_indent {
+            continue;
+        }
+        // Namespaced tasks are quoted (`'lint:fix':`)
+        let name = match trimmed.strip_prefix(['\'', '"']) {
+            Some(quoted) => quoted.split(['\'', '"']).next(),
+            None => trimmed.split_once(':').map(|(name, _)| name),
+        };
+        if let Some(name) = name.filter(|name| is_target_name(name)) {
+            tasks.push(name.to_string());
+        }
+    }
+
+    tasks
+}
+
+/// Detects a Make, just or Task setup and lists the targets it defines
+///
+/// When several are present the first in `TASK_RUNNER_FILES` order wins
+/// (justfile, then Taskfile, then Makefile). Returns None when the project
+/// has none of them.
+pub fn detect_task_runner(path: &Path) -> Option<TaskRunner> {
+    TASK_RUNNER_FILES.iter().find_map(|(file, kind)| {
+        let content = fs::read_to_string(path.join(file)).ok()?;
+        let targets = match *kind {
+            "just" => parse_justfile_recipes(&content),
+            "task" => parse_taskfile_tasks(&content),
+            _ => parse_makefile_targets(&content),
+        };
+
+        Some(TaskRunner {
+            kind: kind.to_string(),
+            file: file.to_string(),
+            targets,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_temp_dir() -> TempDir {
+        tempfile::tempdir().expect("Failed to create temp directory")
+    }
+
+    #[test]
+    fn test_parse_makefile_targets() {
+        let makefile = "\
+CC := gcc
+PORT = 3000
+
+.PHONY: dev build test
+
+dev: install
+\tnpm run dev -- --port $(PORT)
+
+build test: install
+\tnpm run $@
+
+# Not a target: comment
+%.o: %.c
+\t$(CC) -c $<
+
+install:
+\tnpm install
+";
+
+        assert_eq!(
+            parse_makefile_targets(makefile),
+            vec!["dev", "build", "test", "install"]
+        );
+    }
+
+    #[test]
+    fn test_parse_justfile_recipes() {
+        let justfile = "\
+set dotenv-load
+port := \"3000\"
+alias d := dev
+
+# Start the dev server
+dev: install
+    npm run dev -- --port {{port}}
+
+@install:
+    npm install
+
+[confirm]
+deploy env='staging': build
+    ./deploy.sh {{env}}
+
+build:
+    npm run build
+
+_helper:
+    echo private
+";
+
+        assert_eq!(
+            parse_justfile_recipes(justfile),
+            vec!["dev", "install", "deploy", "build"]
+        );
+    }
+
+    #[test]
+    fn test_parse_taskfile_tasks() {
+        let taskfile = "\
+version: '3'
+
+vars:
+  PORT: 3000
+
+tasks:
+  dev:
+    cmds:
+      - npm run dev
+  'lint:fix':
+    cmds:
+      - npm run lint -- --fix
+  build:
+    deps: [install]
+";
+
+        assert_eq!(
+            parse_taskfile_tasks(taskfile),
+            vec!["dev", "lint:fix", "build"]
+        );
+    }
+
+    #[test]
+    fn test_detect_task_runner_prefers_justfile() {
+        let temp_dir = create_temp_dir();
+        fs::write(temp_dir.path().join("Makefile"), "all:\n\techo all\n").unwrap();
+        fs::write(temp_dir.path().join("justfile"), "dev:\n    npm run dev\n").unwrap();
+
+        assert_eq!(
+            detect_task_runner(temp_dir.path()),
+            Some(TaskRunner {
+                kind: "just".to_string(),
+                file: "justfile".to_string(),
+                targets: vec!["dev".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_detect_task_runner_none() {
+        let temp_dir = create_temp_dir();
+        assert_eq!(detect_task_runner(temp_dir.path()), None);
+    }
+}
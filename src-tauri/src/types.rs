@@ -1,16 +1,187 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Project {
     pub name: String,
     pub path: String,
     pub runtime: String,
+    /// Every runtime with a manifest in the project (e.g. package.json and deno.json), primary first
+    pub runtimes: Vec<String>,
     pub package_manager: Option<String>,
+    /// Pinned package manager version, or "1"/"2+" when only yarn's generation is known
+    pub package_manager_version: Option<String>,
+    /// True for package manager versions in maintenance mode (yarn classic)
+    pub package_manager_deprecated: Option<bool>,
     pub port: Option<u16>,
+    /// Vite HMR websocket port, when configured to differ from `port`
+    pub hmr_port: Option<u16>,
+    /// Labeled ports for projects running several dev servers (e.g. web/api), primary first
+    pub ports: Vec<(String, u16)>,
     pub framework: Option<String>,
     pub runtime_version: Option<String>,
     pub scripts: Option<HashMap<String, String>>,
     pub size: Option<u64>,
     pub modified: Option<i64>,
+    /// Creation time (Unix seconds), or the first git commit's date when unavailable
+    pub created: Option<i64>,
+    pub platform_constraints: Option<PlatformConstraints>,
+    /// Whether the package installs commands via package.json `bin` (it ships a CLI)
+    pub has_bin: bool,
+    /// Commands the package installs via `bin`
+    pub bin_names: Vec<String>,
+    pub pinned_runtime_version: Option<String>,
+    pub uses_https: Option<bool>,
+    /// Custom hostname the dev server is configured for (e.g. `myapp.local`), used instead of localhost
+    pub preferred_host: Option<String>,
+    pub version_manager: Option<String>,
+    /// Backend framework serving an API (e.g. "express", "nestjs"), separate from `framework`
+    pub api_framework: Option<String>,
+    /// Primary auth library (e.g. "next-auth", "clerk", "passport")
+    pub auth_lib: Option<String>,
+    /// State management / data-fetching libraries (e.g. "react-query", "zustand")
+    pub data_libs: Vec<String>,
+    /// Analytics/telemetry providers the project sends data to (e.g. "sentry", "next-telemetry")
+    pub telemetry: Vec<String>,
+    /// Whether Next.js telemetry is opted out via `NEXT_TELEMETRY_DISABLED`; None outside Next.js
+    pub next_telemetry_disabled: Option<bool>,
+    /// Dev setting that turns on extra logging (e.g. "DEBUG=app:*", "--verbose")
+    pub dev_verbosity: Option<String>,
+    /// Next.js router in use: "app", "pages" or "both"
+    pub router_type: Option<String>,
+    /// Deployment adapter or Nitro preset (e.g. "vercel", "netlify", "node")
+    pub adapter: Option<String>,
+    /// Primary CSS framework (e.g. "tailwind", "chakra", "sass")
+    pub css_framework: Option<String>,
+    /// Build-time content source: a headless CMS, "astro-content" or "mdx"
+    pub content_source: Option<String>,
+    /// Heuristic: the dev server likely needs a local database started first
+    pub requires_database: Option<bool>,
+    pub ready_pattern: Option<String>,
+    pub has_devcontainer: bool,
+    /// Git hook manager the project enforces hooks with ("husky", "lefthook", "simple-git-hooks")
+    pub git_hooks: Option<String>,
+    pub version_controlled: bool,
+    pub vcs: Option<String>,
+    /// True when the git repo has an `upstream` remote besides `origin`; None outside git
+    pub is_fork: Option<bool>,
+    pub upstream_url: Option<String>,
+    /// Whether the enclosing monorepo's workspace globs include this package; None outside a workspace
+    pub in_workspace: Option<bool>,
+    /// Loader flags the dev/start scripts pass to node (e.g. "--import tsx")
+    pub node_flags: Vec<String>,
+    /// Heap limit requested via `--max-old-space-size` in scripts or .env
+    pub node_memory_limit_mb: Option<u32>,
+    /// Component file types found under src/ (tsx, jsx, vue, svelte, astro), most common first
+    pub source_kinds: Vec<String>,
+}
+
+/// Platforms a package declares support for via package.json `os`/`cpu`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlatformConstraints {
+    pub os: Vec<String>,
+    pub cpu: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthFactor {
+    pub name: String,
+    pub weight: u32,
+    /// None when the factor doesn't apply to the project (e.g. not a git repo)
+    pub passed: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthScore {
+    pub score: u8,
+    pub factors: Vec<HealthFactor>,
+}
+
+/// Working tree state parsed from `git status --porcelain=v2 --branch`
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GitStatus {
+    /// Files with changes in the index
+    pub staged: Vec<String>,
+    /// Files with unstaged changes in the working tree (including merge conflicts)
+    pub modified: Vec<String>,
+    pub untracked: Vec<String>,
+    /// Commits ahead of/behind the upstream branch (0 without an upstream)
+    pub ahead: u32,
+    pub behind: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GitBranches {
+    /// None when HEAD is detached
+    pub current: Option<String>,
+    pub branches: Vec<String>,
+}
+
+/// Settings read from a VS Code devcontainer.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DevcontainerInfo {
+    pub name: Option<String>,
+    /// Ports as written in `forwardPorts`: a number (`3000`) or `host:port` (`db:5432`)
+    pub forward_ports: Vec<String>,
+}
+
+/// A bundle-size limit configured for the project's build
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BudgetEntry {
+    /// Where the budget is configured: "angular" or "bundlesize"
+    pub source: String,
+    /// Angular budget type (plus bundle name for `bundle`) or the bundlesize file glob
+    pub target: String,
+    /// Size that triggers a warning, as written in the config (e.g. "500kb")
+    pub max_warning: Option<String>,
+    /// Size that fails the build
+    pub max_error: Option<String>,
+}
+
+/// A Make, just or Task setup found in a project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskRunner {
+    /// "make", "just" or "task"
+    pub kind: String,
+    /// File the targets were read from (e.g. "justfile")
+    pub file: String,
+    pub targets: Vec<String>,
+}
+
+/// Outcome of opening several projects' remotes at once
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct OpenRemotesResult {
+    /// Paths whose remote was opened
+    pub opened: Vec<String>,
+    /// Paths that couldn't be opened, with the reason
+    pub failed: Vec<(String, crate::error::AppError)>,
+}
+
+/// Vulnerability counts reported by the package manager's audit, by severity
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditSummary {
+    pub info: u64,
+    pub low: u64,
+    pub moderate: u64,
+    pub high: u64,
+    pub critical: u64,
+    pub total: u64,
+}
+
+/// A process the app manages on behalf of a project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunningProcess {
+    pub pid: u32,
+    pub project_path: String,
+    /// The command line the app spawned; None for adopted processes
+    pub command: Option<String>,
+    /// True when the process wasn't spawned by the app this session (no log streaming)
+    pub adopted: bool,
+}
+
+/// Space on the filesystem containing a project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiskInfo {
+    pub total_bytes: u64,
+    pub available_bytes: u64,
 }
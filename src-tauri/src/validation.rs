@@ -1,5 +1,24 @@
 use crate::error::AppError;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Maps an IO error from inspecting a path to an actionable error
+///
+/// A missing path and a path the app isn't allowed to read need different
+/// fixes from the user, so they're reported separately instead of as a
+/// generic IO failure.
+fn map_path_error(path: &str, err: io::Error) -> AppError {
+    match err.kind() {
+        io::ErrorKind::NotFound => AppError::NotFound(format!("Path does not exist: {}", path)),
+        io::ErrorKind::PermissionDenied => AppError::PermissionDenied(format!(
+            "Access denied: {}. Check that the app has permission to read this folder",
+            path
+        )),
+        _ => AppError::IoError(format!("Failed to canonicalize path: {}", err)),
+    }
+}
 
 /// Validates that a path is safe to use
 /// - Checks if path exists
@@ -37,13 +56,11 @@ pub fn validate_directory_path(path: &str) -> Result<PathBuf, AppError> {
 
     let path_buf = PathBuf::from(path);
 
-    // Check if path exists
-    if !path_buf.exists() {
-        return Err(AppError::NotFound(format!("Path does not exist: {}", path)));
-    }
+    // Check if path exists (metadata distinguishes a missing path from an unreadable one)
+    let metadata = fs::metadata(&path_buf).map_err(|e| map_path_error(path, e))?;
 
     // Check if it's a directory
-    if !path_buf.is_dir() {
+    if !metadata.is_dir() {
         return Err(AppError::CommandError(format!(
             "Path is not a directory: {}",
             path
@@ -53,7 +70,7 @@ pub fn validate_directory_path(path: &str) -> Result<PathBuf, AppError> {
     // Resolve to absolute path to prevent symlink attacks
     let canonical = path_buf
         .canonicalize()
-        .map_err(|e| AppError::IoError(format!("Failed to canonicalize path: {}", e)))?;
+        .map_err(|e| map_path_error(path, e))?;
 
     Ok(canonical)
 }
@@ -100,19 +117,41 @@ pub fn validate_file_path(path: &str) -> Result<PathBuf, AppError> {
 
     let path_buf = PathBuf::from(path);
 
-    // Check if path exists
-    if !path_buf.exists() {
-        return Err(AppError::NotFound(format!("Path does not exist: {}", path)));
-    }
+    // Check if path exists (metadata distinguishes a missing path from an unreadable one)
+    fs::metadata(&path_buf).map_err(|e| map_path_error(path, e))?;
 
     // Resolve to absolute path
     let canonical = path_buf
         .canonicalize()
-        .map_err(|e| AppError::IoError(format!("Failed to canonicalize path: {}", e)))?;
+        .map_err(|e| map_path_error(path, e))?;
 
     Ok(canonical)
 }
 
+/// Validates a shell chosen by the user to run commands through
+///
+/// The shell must be given as an absolute path to an existing file. The path
+/// is returned as given (not canonicalized) so the shell's name, which picks
+/// the script syntax, survives symlinks like `/bin/sh -> dash`.
+pub fn validate_shell_path(shell: &str) -> Result<String, AppError> {
+    if !Path::new(shell).is_absolute() {
+        return Err(AppError::CommandError(format!(
+            "Invalid shell: '{}' must be an absolute path",
+            shell
+        )));
+    }
+
+    let canonical = validate_file_path(shell)?;
+    if !canonical.is_file() {
+        return Err(AppError::CommandError(format!(
+            "Invalid shell: {} is not a file",
+            shell
+        )));
+    }
+
+    Ok(shell.to_string())
+}
+
 /// Validates a process ID
 pub fn validate_pid(pid: u32) -> Result<u32, AppError> {
     // PID 0 is reserved for the kernel/swapper and is not a valid user-space process ID
@@ -179,6 +218,92 @@ pub fn validate_command(command: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+/// Validates a path the app is about to write to
+/// - The file doesn't need to exist yet, but its parent directory must
+/// - Prevents path traversal attacks
+/// - Refuses paths that point at an existing directory
+pub fn validate_output_file_path(path: &str) -> Result<PathBuf, AppError> {
+    if path.is_empty() {
+        return Err(AppError::NotFound("Path cannot be empty".to_string()));
+    }
+
+    let path_buf = PathBuf::from(path);
+    let file_name = path_buf.file_name().ok_or_else(|| {
+        AppError::CommandError(format!("Invalid path: '{}' has no file name", path))
+    })?;
+
+    // An empty parent means a relative file name with no directory part
+    let parent = match path_buf.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => {
+            return Err(AppError::CommandError(
+                "Invalid path: an absolute destination path is required".to_string(),
+            ))
+        }
+    };
+
+    // Null bytes, traversal and length are checked on the parent directory
+    let parent = validate_directory_path(&parent.to_string_lossy())?;
+    let destination = parent.join(file_name);
+
+    if destination.is_dir() {
+        return Err(AppError::CommandError(format!(
+            "Invalid path: '{}' is a directory",
+            path
+        )));
+    }
+
+    Ok(destination)
+}
+
+/// Validates a git branch name before passing it to git
+///
+/// Only a conservative subset of git's ref-name rules is accepted: ASCII
+/// letters, digits, `.`, `_`, `-` and `/`. Names can't start with `-` (which
+/// git would parse as an option) and must satisfy git's structural rules
+/// (no `..`, `//`, trailing `/` or `.`, or `.lock` suffix).
+pub fn validate_branch_name(branch: &str) -> Result<(), AppError> {
+    if branch.is_empty() {
+        return Err(AppError::CommandError(
+            "Branch name cannot be empty".to_string(),
+        ));
+    }
+
+    if branch.len() > 255 {
+        return Err(AppError::CommandError(
+            "Invalid branch name: too long".to_string(),
+        ));
+    }
+
+    if let Some(c) = branch
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '/')))
+    {
+        return Err(AppError::CommandError(format!(
+            "Invalid branch name: '{}' contains invalid character '{}'",
+            branch, c
+        )));
+    }
+
+    let malformed = branch.starts_with('-')
+        || branch.starts_with('/')
+        || branch.starts_with('.')
+        || branch.ends_with('/')
+        || branch.ends_with('.')
+        || branch.ends_with(".lock")
+        || branch.contains("..")
+        || branch.contains("//")
+        || branch.contains("/.");
+    if malformed {
+        return Err(AppError::CommandError(format!(
+            "Invalid branch name: '{}' is not a valid ref name",
+            branch
+        )));
+    }
+
+    Ok(())
+}
+
 /// Validates command arguments to prevent injection attacks
 /// 
 /// Arguments are checked for dangerous shell metacharacters that could allow
@@ -223,10 +348,53 @@ pub fn validate_command_args(args: &[String]) -> Result<(), AppError> {
     Ok(())
 }
 
+/// Validates environment variables passed to a spawned process
+///
+/// Names must be plain shell identifiers since they're written unquoted into
+/// the spawn script. Values only ever travel through the process environment,
+/// so only null bytes (which can't be passed to a process) are rejected.
+pub fn validate_env_vars(env: &HashMap<String, String>) -> Result<(), AppError> {
+    if env.len() > 100 {
+        return Err(AppError::CommandError(format!(
+            "Too many environment variables: {} (maximum 100 allowed)",
+            env.len()
+        )));
+    }
+
+    for (name, value) in env {
+        let is_identifier = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_identifier {
+            return Err(AppError::CommandError(format!(
+                "Invalid environment variable name: '{}'",
+                name
+            )));
+        }
+
+        if value.len() > 4096 {
+            return Err(AppError::CommandError(format!(
+                "Environment variable {} is too long: {} characters (maximum 4096)",
+                name,
+                value.len()
+            )));
+        }
+        if value.contains('\0') {
+            return Err(AppError::CommandError(format!(
+                "Invalid environment variable {}: contains null bytes",
+                name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
 
     #[test]
     fn test_validate_pid_rejects_zero() {
@@ -294,6 +462,20 @@ mod tests {
         assert!(matches!(err, AppError::NotFound(_)));
     }
 
+    #[test]
+    fn test_validate_shell_path() {
+        assert_eq!(validate_shell_path("/bin/sh").unwrap(), "/bin/sh");
+        assert!(matches!(
+            validate_shell_path("nu"),
+            Err(AppError::CommandError(_))
+        ));
+        assert!(matches!(
+            validate_shell_path("/bin"),
+            Err(AppError::CommandError(_))
+        ));
+        assert!(validate_shell_path("/nonexistent/bin/nu").is_err());
+    }
+
     #[test]
     fn test_validate_file_path_rejects_empty() {
         assert!(validate_file_path("").is_err());
@@ -462,4 +644,115 @@ mod tests {
         assert!(validate_command_args(&["--port".to_string(), "4321".to_string()]).is_ok());
         assert!(validate_command_args(&["--port=4321".to_string()]).is_ok());
     }
+
+    #[test]
+    fn test_validate_branch_name_accepts_valid() {
+        assert!(validate_branch_name("main").is_ok());
+        assert!(validate_branch_name("feature/login-form").is_ok());
+        assert!(validate_branch_name("release-1.2.0").is_ok());
+        assert!(validate_branch_name("fix_typo").is_ok());
+    }
+
+    #[test]
+    fn test_validate_branch_name_rejects_metacharacters() {
+        assert!(validate_branch_name("main; rm -rf /").is_err());
+        assert!(validate_branch_name("main&&evil").is_err());
+        assert!(validate_branch_name("$(whoami)").is_err());
+        assert!(validate_branch_name("feature branch").is_err());
+        let err = validate_branch_name("main|cat").unwrap_err();
+        assert!(matches!(err, AppError::CommandError(_)));
+        assert!(err.to_string().contains("invalid character"));
+    }
+
+    #[test]
+    fn test_validate_branch_name_rejects_malformed_refs() {
+        assert!(validate_branch_name("").is_err());
+        assert!(validate_branch_name("-f").is_err());
+        assert!(validate_branch_name("feature/").is_err());
+        assert!(validate_branch_name("a..b").is_err());
+        assert!(validate_branch_name("a//b").is_err());
+        assert!(validate_branch_name("main.lock").is_err());
+        assert!(validate_branch_name("feature/.hidden").is_err());
+    }
+
+    #[test]
+    fn test_validate_env_vars() {
+        let mut env = HashMap::new();
+        env.insert("NODE_ENV".to_string(), "development".to_string());
+        env.insert("API_URL".to_string(), "http://localhost:4000/?a=1&b=$x".to_string());
+        assert!(validate_env_vars(&env).is_ok());
+
+        for name in ["", "1PORT", "API-URL", "A;B", "$(whoami)"] {
+            let env = HashMap::from([(name.to_string(), "value".to_string())]);
+            let err = validate_env_vars(&env).unwrap_err();
+            assert!(matches!(err, AppError::CommandError(_)));
+            assert!(err.to_string().contains("Invalid environment variable name"));
+        }
+
+        let env = HashMap::from([("TOKEN".to_string(), "a\0b".to_string())]);
+        assert!(validate_env_vars(&env).is_err());
+    }
+
+    #[test]
+    fn test_validate_output_file_path_accepts_new_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dest = temp_dir.path().join("logs.txt");
+
+        let validated = validate_output_file_path(&dest.to_string_lossy()).unwrap();
+        assert_eq!(validated.file_name().unwrap(), "logs.txt");
+        assert!(validated.is_absolute());
+    }
+
+    #[test]
+    fn test_validate_output_file_path_rejects_invalid() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        assert!(validate_output_file_path("").is_err());
+        assert!(validate_output_file_path("logs.txt").is_err());
+        assert!(validate_output_file_path("/nonexistent/dir/12345/logs.txt").is_err());
+        assert!(validate_output_file_path(&temp_dir.path().to_string_lossy()).is_err());
+
+        let traversal = format!("{}/../logs.txt", temp_dir.path().display());
+        assert!(validate_output_file_path(&traversal).is_err());
+    }
+
+    #[test]
+    fn test_map_path_error_kinds() {
+        let not_found = map_path_error("/missing", io::Error::from(io::ErrorKind::NotFound));
+        assert!(matches!(not_found, AppError::NotFound(_)));
+        assert!(not_found.to_string().contains("Path does not exist: /missing"));
+
+        let denied = map_path_error(
+            "/protected",
+            io::Error::from(io::ErrorKind::PermissionDenied),
+        );
+        assert!(matches!(denied, AppError::PermissionDenied(_)));
+        assert!(denied.to_string().contains("Access denied: /protected"));
+
+        let other = map_path_error("/other", io::Error::from(io::ErrorKind::InvalidData));
+        assert!(matches!(other, AppError::IoError(_)));
+        assert!(other.to_string().contains("Failed to canonicalize path"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_validate_directory_path_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let locked = temp_dir.path().join("locked");
+        let inner = locked.join("inner");
+        fs::create_dir_all(&inner).unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+
+        let result = validate_directory_path(&inner.to_string_lossy());
+        // Permission checks don't apply when running as root
+        let bypasses_permissions = fs::read_dir(&locked).is_ok();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+
+        if bypasses_permissions {
+            return;
+        }
+        assert!(matches!(result, Err(AppError::PermissionDenied(_))));
+    }
 }
@@ -0,0 +1,390 @@
+use crate::error::AppError;
+use crate::project_info::SKIP_DIRS;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+use tauri::{AppHandle, Emitter};
+
+/// How often the project tree is polled for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Quiet period after the last change before a burst is emitted
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(300);
+
+// Active watchers keyed by canonical project path; the flag is set to stop the watcher thread
+static WATCHERS: OnceLock<Mutex<HashMap<String, Arc<AtomicBool>>>> = OnceLock::new();
+
+fn get_watchers() -> &'static Mutex<HashMap<String, Arc<AtomicBool>>> {
+    WATCHERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The WATCHERS key for a project path, so different spellings of a path share a watcher
+///
+/// Falls back to the path as given when it no longer validates (e.g. the
+/// project was deleted), so its watcher can still be stopped.
+fn watcher_key(project_path: &str) -> String {
+    crate::validation::validate_directory_path(project_path)
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|_| project_path.to_string())
+}
+
+/// Removes a watcher's entry, unless it has already been replaced by a newer watcher
+fn remove_watcher(key: &str, stop_flag: &Arc<AtomicBool>) {
+    let mut watchers = get_watchers()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if watchers
+        .get(key)
+        .is_some_and(|active| Arc::ptr_eq(active, stop_flag))
+    {
+        watchers.remove(key);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+impl ChangeKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ChangeKind::Created => "created",
+            ChangeKind::Modified => "modified",
+            ChangeKind::Removed => "removed",
+        }
+    }
+}
+
+/// Coalesces bursts of file changes
+///
+/// Changes are held until no new change has arrived for the debounce window,
+/// so saving many files at once (or an editor writing a file several times)
+/// results in a single batch with one entry per path.
+pub struct Debouncer {
+    window: Duration,
+    pending: HashMap<String, ChangeKind>,
+    last_change: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(window: Duration) -> Self {
+        Debouncer {
+            window,
+            pending: HashMap::new(),
+            last_change: None,
+        }
+    }
+
+    /// Records a change, merging it with any pending change for the same path
+    pub fn push(&mut self, path: String, kind: ChangeKind, now: Instant) {
+        let merged = match (self.pending.get(&path), kind) {
+            // A file created and modified within one burst is still just created
+            (Some(ChangeKind::Created), ChangeKind::Modified) => ChangeKind::Created,
+            // A file removed and recreated within one burst was effectively modified
+            (Some(ChangeKind::Removed), ChangeKind::Created) => ChangeKind::Modified,
+            _ => kind,
+        };
+        self.pending.insert(path, merged);
+        self.last_change = Some(now);
+    }
+
+    /// Returns the pending changes once the burst has settled
+    pub fn drain_ready(&mut self, now: Instant) -> Vec<(String, ChangeKind)> {
+        match self.last_change {
+            Some(last) if now.duration_since(last) >= self.window => {
+                self.last_change = None;
+                let mut changes: Vec<(String, ChangeKind)> = self.pending.drain().collect();
+                changes.sort_by(|a, b| a.0.cmp(&b.0));
+                changes
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Records the modification time of every file in the project (skipping ignored dirs)
+fn snapshot(root: &Path) -> HashMap<PathBuf, SystemTime> {
+    fn walk(path: &Path, files: &mut HashMap<PathBuf, SystemTime>) {
+        let entries = match fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        for entry in entries.flatten() {
+            let entry_path = entry.path();
+            let metadata = match entry_path.metadata() {
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+
+            if metadata.is_dir() {
+                let skipped = entry_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|name| SKIP_DIRS.contains(&name));
+                if !skipped {
+                    walk(&entry_path, files);
+                }
+            } else if let Ok(modified) = metadata.modified() {
+                files.insert(entry_path, modified);
+            }
+        }
+    }
+
+    let mut files = HashMap::new();
+    walk(root, &mut files);
+    files
+}
+
+/// Compares two snapshots and returns the changed paths
+fn diff_snapshots(
+    before: &HashMap<PathBuf, SystemTime>,
+    after: &HashMap<PathBuf, SystemTime>,
+) -> Vec<(PathBuf, ChangeKind)> {
+    let mut changes: Vec<(PathBuf, ChangeKind)> = after
+        .iter()
+        .filter_map(|(path, modified)| match before.get(path) {
+            None => Some((path.clone(), ChangeKind::Created)),
+            Some(previous) if previous != modified => Some((path.clone(), ChangeKind::Modified)),
+            _ => None,
+        })
+        .collect();
+
+    changes.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .map(|path| (path.clone(), ChangeKind::Removed)),
+    );
+
+    changes
+}
+
+/// Starts watching a project for file changes
+///
+/// Emits a `project-file-changed` event for each changed file once a burst of
+/// changes settles. Each project gets its own watcher; calling this again for
+/// a project that is already watched is a no-op.
+#[tauri::command]
+pub fn watch_project(app: AppHandle, project_path: String) -> Result<(), AppError> {
+    let validated_path = crate::validation::validate_directory_path(&project_path)?;
+    let key = validated_path.to_string_lossy().to_string();
+
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    {
+        let mut watchers = get_watchers()
+            .lock()
+            .map_err(|_| AppError::ProcessError("Watcher registry is unavailable".to_string()))?;
+        if watchers.contains_key(&key) {
+            return Ok(());
+        }
+        watchers.insert(key.clone(), stop_flag.clone());
+    }
+
+    let thread_key = key.clone();
+    let thread_stop_flag = stop_flag.clone();
+    let spawned = std::thread::Builder::new()
+        .name(format!("project-watcher-{}", key))
+        .spawn(move || {
+            watch_until_stopped(&app, &project_path, &validated_path, &thread_stop_flag);
+            // Also reached when emitting failed, so the project can be watched again
+            remove_watcher(&thread_key, &thread_stop_flag);
+        });
+
+    if let Err(e) = spawned {
+        remove_watcher(&key, &stop_flag);
+        return Err(AppError::ProcessError(format!(
+            "Failed to spawn watcher thread: {}",
+            e
+        )));
+    }
+
+    Ok(())
+}
+
+/// Polls the project tree and emits its changes until `stop_flag` is set or emitting fails
+fn watch_until_stopped(
+    app: &AppHandle,
+    project_path: &str,
+    validated_path: &Path,
+    stop_flag: &AtomicBool,
+) {
+    let mut previous = snapshot(validated_path);
+    let mut debouncer = Debouncer::new(DEBOUNCE_WINDOW);
+
+    while !stop_flag.load(Ordering::Relaxed) {
+        std::thread::sleep(POLL_INTERVAL);
+
+        let current = snapshot(validated_path);
+        let now = Instant::now();
+        for (path, kind) in diff_snapshots(&previous, &current) {
+            debouncer.push(path.to_string_lossy().to_string(), kind, now);
+        }
+        previous = current;
+
+        for (path, kind) in debouncer.drain_ready(Instant::now()) {
+            // If emit fails, the app is shutting down, so stop watching
+            if app
+                .emit(
+                    "project-file-changed",
+                    serde_json::json!({
+                        "projectPath": project_path,
+                        "path": path,
+                        "kind": kind.as_str()
+                    }),
+                )
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+/// Stops the watcher started by `watch_project`
+#[tauri::command]
+pub fn stop_watch_project(project_path: String) -> Result<(), AppError> {
+    let stop_flag = get_watchers()
+        .lock()
+        .map_err(|_| AppError::ProcessError("Watcher registry is unavailable".to_string()))?
+        .remove(&watcher_key(&project_path))
+        .ok_or_else(|| {
+            AppError::NotFound(format!("No watcher running for project: {}", project_path))
+        })?;
+
+    stop_flag.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debouncer_waits_for_quiet_period() {
+        let mut debouncer = Debouncer::new(Duration::from_millis(300));
+        let start = Instant::now();
+
+        debouncer.push("a.ts".to_string(), ChangeKind::Modified, start);
+        assert!(debouncer
+            .drain_ready(start + Duration::from_millis(100))
+            .is_empty());
+
+        // A new change within the window extends the burst
+        debouncer.push(
+            "b.ts".to_string(),
+            ChangeKind::Created,
+            start + Duration::from_millis(200),
+        );
+        assert!(debouncer
+            .drain_ready(start + Duration::from_millis(400))
+            .is_empty());
+
+        let changes = debouncer.drain_ready(start + Duration::from_millis(500));
+        assert_eq!(
+            changes,
+            vec![
+                ("a.ts".to_string(), ChangeKind::Modified),
+                ("b.ts".to_string(), ChangeKind::Created),
+            ]
+        );
+
+        // Drained changes aren't emitted twice
+        assert!(debouncer
+            .drain_ready(start + Duration::from_secs(2))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_debouncer_merges_changes_to_same_path() {
+        let mut debouncer = Debouncer::new(Duration::from_millis(300));
+        let start = Instant::now();
+
+        debouncer.push("a.ts".to_string(), ChangeKind::Created, start);
+        debouncer.push("a.ts".to_string(), ChangeKind::Modified, start);
+        debouncer.push("b.ts".to_string(), ChangeKind::Removed, start);
+        debouncer.push("b.ts".to_string(), ChangeKind::Created, start);
+
+        let changes = debouncer.drain_ready(start + Duration::from_millis(300));
+        assert_eq!(
+            changes,
+            vec![
+                ("a.ts".to_string(), ChangeKind::Created),
+                ("b.ts".to_string(), ChangeKind::Modified),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+
+        let before: HashMap<PathBuf, SystemTime> = [
+            (PathBuf::from("kept.ts"), t0),
+            (PathBuf::from("changed.ts"), t0),
+            (PathBuf::from("removed.ts"), t0),
+        ]
+        .into_iter()
+        .collect();
+        let after: HashMap<PathBuf, SystemTime> = [
+            (PathBuf::from("kept.ts"), t0),
+            (PathBuf::from("changed.ts"), t1),
+            (PathBuf::from("new.ts"), t1),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut changes = diff_snapshots(&before, &after);
+        changes.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            changes,
+            vec![
+                (PathBuf::from("changed.ts"), ChangeKind::Modified),
+                (PathBuf::from("new.ts"), ChangeKind::Created),
+                (PathBuf::from("removed.ts"), ChangeKind::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stop_watch_project_not_found() {
+        let result = stop_watch_project("/nonexistent/project/12345".to_string());
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_watcher_key_canonicalizes_path() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let path = temp_dir.path().to_string_lossy().to_string();
+
+        assert_eq!(watcher_key(&format!("{}/.", path)), watcher_key(&path));
+        assert_eq!(
+            watcher_key("/nonexistent/project/12345"),
+            "/nonexistent/project/12345"
+        );
+    }
+
+    #[test]
+    fn test_remove_watcher_keeps_newer_watcher() {
+        let key = "/tmp/runstack-test-remove-watcher";
+        let old_flag = Arc::new(AtomicBool::new(false));
+        let new_flag = Arc::new(AtomicBool::new(false));
+        get_watchers()
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), new_flag.clone());
+
+        remove_watcher(key, &old_flag);
+        assert!(get_watchers().lock().unwrap().contains_key(key));
+
+        remove_watcher(key, &new_flag);
+        assert!(!get_watchers().lock().unwrap().contains_key(key));
+    }
+}
@@ -0,0 +1,319 @@
+use crate::error::AppError;
+use crate::project_info::{glob_matches, read_package_json, SKIP_DIRS};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Dependency fields that can reference another workspace package
+const DEPENDENCY_FIELDS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// Reads the `packages` list of a pnpm-workspace.yaml
+fn parse_pnpm_workspace(content: &str) -> Vec<String> {
+    let mut patterns = Vec::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+        if !in_packages {
+            continue;
+        }
+
+        if let Some(pattern) = trimmed.strip_prefix('-') {
+            let pattern = pattern.trim().trim_matches(|c| c == '"' || c == '\'');
+            if !pattern.is_empty() {
+                patterns.push(pattern.to_string());
+            }
+        }
+    }
+
+    patterns
+}
+
+/// Reads the workspace package globs from package.json `workspaces` or pnpm-workspace.yaml
+///
+/// Returns None when the directory isn't a workspace root.
+fn workspace_patterns(root: &Path) -> Option<Vec<String>> {
+    if let Ok(content) = fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        return Some(parse_pnpm_workspace(&content));
+    }
+
+    // `workspaces` is either an array or `{ "packages": [...] }` (yarn classic)
+    let package_json = read_package_json(root)?;
+    let workspaces = package_json.get("workspaces")?;
+    let patterns = workspaces
+        .as_array()
+        .or_else(|| workspaces.get("packages")?.as_array())?;
+
+    Some(
+        patterns
+            .iter()
+            .filter_map(|pattern| pattern.as_str().map(String::from))
+            .collect(),
+    )
+}
+
+/// Lists the subdirectories of `dir` a workspace glob can descend into
+fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            !name.starts_with('.') && !SKIP_DIRS.contains(&name.as_ref())
+        })
+        .map(|entry| entry.path())
+        .collect()
+}
+
+/// Expands a workspace glob (`packages/*`, `apps/**`, `tools/cli`) into directories
+fn expand_pattern(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut dirs = vec![root.to_path_buf()];
+
+    for segment in pattern.trim_start_matches("./").split('/') {
+        dirs = match segment {
+            "" | "." => dirs,
+            // `**` matches the directory itself and everything below it
+            "**" => {
+                let mut matched = Vec::new();
+                let mut pending = dirs;
+                while let Some(dir) = pending.pop() {
+                    pending.extend(child_dirs(&dir));
+                    matched.push(dir);
+                }
+                matched
+            }
+            _ if segment.contains(['*', '?']) => dirs
+                .iter()
+                .flat_map(|dir| child_dirs(dir))
+                .filter(|dir| {
+                    dir.file_name()
+                        .is_some_and(|name| glob_matches(segment, &name.to_string_lossy()))
+                })
+                .collect(),
+            _ => dirs
+                .iter()
+                .map(|dir| dir.join(segment))
+                .filter(|dir| dir.is_dir())
+                .collect(),
+        };
+    }
+
+    dirs
+}
+
+/// Resolves the directories the workspace globs include
+///
+/// Negated patterns (`!packages/legacy`) exclude matching directories.
+fn workspace_dirs(root: &Path) -> Option<Vec<PathBuf>> {
+    let patterns = workspace_patterns(root)?;
+
+    let (excludes, includes): (Vec<&String>, Vec<&String>) = patterns
+        .iter()
+        .partition(|pattern| pattern.starts_with('!'));
+    let excluded: Vec<PathBuf> = excludes
+        .iter()
+        .flat_map(|pattern| expand_pattern(root, &pattern[1..]))
+        .collect();
+
+    Some(
+        includes
+            .iter()
+            .flat_map(|pattern| expand_pattern(root, pattern))
+            .filter(|dir| !excluded.contains(dir))
+            .collect(),
+    )
+}
+
+/// Resolves the workspace's packages as (package name, package.json contents)
+///
+/// Directories without a named package.json are skipped.
+fn workspace_packages(root: &Path) -> Option<BTreeMap<String, serde_json::Value>> {
+    let packages = workspace_dirs(root)?
+        .into_iter()
+        .filter_map(|dir| {
+            let package_json = read_package_json(&dir)?;
+            let name = package_json.get("name")?.as_str()?.to_string();
+            Some((name, package_json))
+        })
+        .collect();
+
+    Some(packages)
+}
+
+/// Checks whether a package is included by the nearest enclosing workspace
+///
+/// Returns None when no parent directory is a workspace root, and false for
+/// packages inside a monorepo that its workspace globs don't pick up.
+pub fn in_workspace(path: &Path) -> Option<bool> {
+    let root = path
+        .ancestors()
+        .skip(1)
+        .find(|dir| workspace_patterns(dir).is_some())?;
+
+    Some(workspace_dirs(root)?.iter().any(|dir| dir == path))
+}
+
+/// Builds the internal dependency graph of a monorepo
+///
+/// Returns each workspace package (sorted by name) with the other workspace
+/// packages it depends on. External dependencies are left out.
+pub fn workspace_graph(root: &Path) -> Result<Vec<(String, Vec<String>)>, AppError> {
+    let packages = workspace_packages(root).ok_or_else(|| {
+        AppError::NotFound(format!("No workspaces configured in: {}", root.display()))
+    })?;
+
+    let graph = packages
+        .iter()
+        .map(|(name, package_json)| {
+            let mut internal: Vec<String> = DEPENDENCY_FIELDS
+                .iter()
+                .filter_map(|field| package_json.get(field)?.as_object())
+                .flat_map(|dependencies| dependencies.keys())
+                .filter(|dependency| *dependency != name && packages.contains_key(*dependency))
+                .cloned()
+                .collect();
+            internal.sort();
+            internal.dedup();
+            (name.clone(), internal)
+        })
+        .collect();
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_temp_dir() -> TempDir {
+        tempfile::tempdir().expect("Failed to create temp directory")
+    }
+
+    fn create_package(root: &Path, dir: &str, package_json: &str) {
+        let package_dir = root.join(dir);
+        fs::create_dir_all(&package_dir).expect("Failed to create package dir");
+        fs::write(package_dir.join("package.json"), package_json)
+            .expect("Failed to write package.json");
+    }
+
+    #[test]
+    fn test_parse_pnpm_workspace() {
+        let content = "\
+packages:
+  # all packages in direct subdirs of packages/
+  - 'packages/*'
+  - \"apps/**\"
+  - '!**/test/**'
+catalog:
+  react: ^18.2.0
+";
+
+        assert_eq!(
+            parse_pnpm_workspace(content),
+            vec!["packages/*", "apps/**", "!**/test/**"]
+        );
+    }
+
+    #[test]
+    fn test_workspace_graph_internal_dependencies() {
+        let temp_dir = create_temp_dir();
+        let root = temp_dir.path();
+        create_package(
+            root,
+            "",
+            r#"{"name": "monorepo", "private": true, "workspaces": ["packages/*"]}"#,
+        );
+        create_package(
+            root,
+            "packages/a",
+            r#"{"name": "@acme/a", "dependencies": {"lodash": "^4.17.21"}}"#,
+        );
+        create_package(
+            root,
+            "packages/b",
+            r#"{"name": "@acme/b", "dependencies": {"@acme/a": "workspace:*", "react": "^18.2.0"}}"#,
+        );
+
+        assert_eq!(
+            workspace_graph(root).unwrap(),
+            vec![
+                ("@acme/a".to_string(), vec![]),
+                ("@acme/b".to_string(), vec!["@acme/a".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_workspace_graph_pnpm_with_exclusion() {
+        let temp_dir = create_temp_dir();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("pnpm-workspace.yaml"),
+            "packages:\n  - 'apps/*'\n  - 'packages/*'\n  - '!packages/legacy'\n",
+        )
+        .unwrap();
+        create_package(root, "packages/ui", r#"{"name": "ui"}"#);
+        create_package(root, "packages/legacy", r#"{"name": "legacy"}"#);
+        create_package(
+            root,
+            "apps/web",
+            r#"{"name": "web", "devDependencies": {"ui": "workspace:^", "legacy": "1.0.0"}}"#,
+        );
+
+        assert_eq!(
+            workspace_graph(root).unwrap(),
+            vec![
+                ("ui".to_string(), vec![]),
+                ("web".to_string(), vec!["ui".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_in_workspace_outside_globs() {
+        let temp_dir = create_temp_dir();
+        let root = temp_dir.path();
+        create_package(
+            root,
+            "",
+            r#"{"name": "monorepo", "workspaces": ["packages/*"]}"#,
+        );
+        create_package(root, "packages/ui", r#"{"name": "@acme/ui"}"#);
+        create_package(root, "tools/scripts", r#"{"name": "@acme/scripts"}"#);
+
+        assert_eq!(in_workspace(&root.join("packages/ui")), Some(true));
+        assert_eq!(in_workspace(&root.join("tools/scripts")), Some(false));
+        // The root itself has no enclosing workspace
+        assert_eq!(in_workspace(root), None);
+    }
+
+    #[test]
+    fn test_workspace_graph_not_a_workspace() {
+        let temp_dir = create_temp_dir();
+        create_package(temp_dir.path(), "", r#"{"name": "app"}"#);
+
+        assert!(matches!(
+            workspace_graph(temp_dir.path()),
+            Err(AppError::NotFound(_))
+        ));
+    }
+}